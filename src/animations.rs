@@ -34,7 +34,7 @@ use serde::Deserialize;
 
 use crate::{
     animations::prelude::*, characters::prelude::*, core::prelude::*, log::prelude::*,
-    render::prelude::*, screens::prelude::*, utils::prelude::*,
+    procgen::prelude::*, render::prelude::*, screens::prelude::*, utils::prelude::*,
 };
 
 pub(super) struct AnimationsPlugin;
@@ -43,11 +43,18 @@ impl Plugin for AnimationsPlugin {
         app.add_plugins(SpritesheetAnimationPlugin);
 
         app.add_systems(Startup, setup_rng::<AnimationRng>);
+        app.add_systems(
+            OnEnter(Screen::Gameplay),
+            seed_rng::<AnimationRng>
+                .after(EnterGameplaySystems::LoadSave)
+                .before(EnterGameplaySystems::Animations),
+        );
         app.add_systems(
             OnEnter(Screen::Gameplay),
             (
                 sprites::setup_animations::<Player>,
                 sprites::setup_animations::<Slime>,
+                sprites::setup_animations::<Villager>,
             )
                 .in_set(EnterGameplaySystems::Animations),
         );
@@ -66,6 +73,12 @@ impl Plugin for AnimationsPlugin {
                     audio::update_animation_sounds::<Slime, SlimeAssets>,
                 )
                     .chain(),
+                (
+                    sprites::update_animations::<Villager>,
+                    sprites::update_animation_orientations::<Villager>,
+                    audio::update_animation_sounds::<Villager, VillagerAssets>,
+                )
+                    .chain(),
             )
                 .run_if(in_state(Screen::Gameplay))
                 .in_set(AppSystems::Update)
@@ -111,6 +124,8 @@ where
     pub(crate) run_clips: Option<[AnimationClip; 3]>,
     #[serde(default)]
     pub(crate) jump_clips: Option<[AnimationClip; 3]>,
+    #[serde(default)]
+    pub(crate) dash_clips: Option<[AnimationClip; 3]>,
     #[serde(skip)]
     pub(crate) _phantom: PhantomData<T>,
 }
@@ -136,6 +151,7 @@ where
     // FIXME: We should use fields prefixed with `_`
     pub(crate) _run_clips: Option<[AnimationClip; 3]>,
     pub(crate) jump_clips: Option<[AnimationClip; 3]>,
+    pub(crate) dash_clips: Option<[AnimationClip; 3]>,
     pub(crate) _phantom: PhantomData<T>,
 }
 
@@ -213,6 +229,7 @@ pub(crate) enum AnimationAction {
     Idle,
     Walk,
     Jump,
+    Dash,
 }
 impl AnimationAction {
     pub(crate) const WALK: u8 = Self::Walk as u8;
@@ -333,6 +350,6 @@ impl AnimationClip {
 pub(crate) struct AnimationTimer(pub(crate) Timer);
 
 /// Rng for animations
-#[derive(Component, Default)]
+#[derive(Component, Default, TypePath)]
 pub(crate) struct AnimationRng;
 impl ForkedRng for AnimationRng {}
@@ -45,10 +45,11 @@ pub(super) fn setup_animations<T>(
         ..default()
     };
 
-    let (idle_clips, walk_clips, jump_clips) = (
+    let (idle_clips, walk_clips, jump_clips, dash_clips) = (
         animation_data.idle_clips.as_ref(),
         animation_data.walk_clips.as_ref(),
         animation_data.jump_clips.as_ref(),
+        animation_data.dash_clips.as_ref(),
     );
     // NOTE: This asserts that each direction of the clip is the same length.
     assert!(
@@ -93,6 +94,21 @@ pub(super) fn setup_animations<T>(
             AnimationRepeat::Times(1),
         );
     }
+    if let Some(dash_clips) = dash_clips {
+        // NOTE: This asserts that each direction of the clip is the same length.
+        assert!(
+            dash_clips
+                .windows(2)
+                .all(|c| { c[0].sprite_coords.len() == c[1].sprite_coords.len() })
+        );
+        sprite_animations.insert_clips(
+            dash_clips,
+            &mut animations,
+            &base_sheet,
+            floating_sheet,
+            AnimationRepeat::Times(1),
+        );
+    }
 
     commands.insert_resource(sprite_animations);
 }
@@ -1,24 +1,60 @@
 //! Characters
 
+mod archetype;
 mod attack;
+mod boss;
+mod equipment;
+mod experience;
 mod health;
+mod hit;
+mod loot;
 mod movement;
 mod nav;
 mod npc;
 mod player;
+mod projectile;
+mod status;
+mod wander;
 
 #[allow(unused_imports)]
 pub(crate) mod prelude {
+    pub(crate) use super::archetype::{
+        Archetype, ArchetypeBehavior, ArchetypeData, ArchetypeDataCache, ArchetypeHandle,
+        ArchetypeIndex, NavRange, Tint, filter_attacks, spawn_archetypes,
+    };
     pub(crate) use super::attack::{
-        AimDirection, Attack, AttackData, AttackStats, AttackTimer, DelayAttack, InitAttack, punch,
+        AimDirection, Attack, AttackData, AttackDataCache, AttackHandle, AttackKind, AttackStats,
+        AttackTimer, DelayAttack, InitAttack,
+    };
+    pub(crate) use super::boss::{Boss, BossFight, BossPhase};
+    pub(crate) use super::equipment::{
+        Equipment, EquipmentSlot, Inventory, Item, ItemData, ItemDataCache, ItemHandle,
+    };
+    pub(crate) use super::experience::{
+        Experience, ExperienceReward, GainExperience, LevelUp, ProgressionData,
+        ProgressionDataCache, ProgressionHandle,
+    };
+    pub(crate) use super::health::{
+        CharacterDied, Damage, DamageSource, Health, HealthRegen, PlayerDied,
+    };
+    pub(crate) use super::hit::{HitReaction, HitStunTimer, InvulnerabilityTimer, Knockback};
+    pub(crate) use super::loot::{
+        Loot, LootData, LootDataCache, LootDrop, LootDropIndex, LootDropKind, LootHandle, LootKind,
+        LootRng, LootTable,
     };
-    pub(crate) use super::health::{Damage, Health};
     pub(crate) use super::movement::{
-        FacingDirection, JUMP_DURATION_SECS, JumpHeight, JumpTimer, WalkSpeed,
+        DASH_DURATION_SECS, DashCooldownTimer, DashMotion, DashTimer, FacingDirection,
+        JUMP_DURATION_SECS, JumpHeight, JumpTimer, WalkSpeed,
     };
     pub(crate) use super::nav::{NavTarget, Navigator, Path};
-    pub(crate) use super::npc::{Npc, Slime, SlimeAssets};
+    pub(crate) use super::npc::{Npc, Slime, SlimeAssets, Villager, VillagerAssets};
     pub(crate) use super::player::{Player, PlayerAssets};
+    pub(crate) use super::projectile::{Projectile, ProjectileData, ProjectileTimer};
+    pub(crate) use super::status::{
+        ApplyStatusEffect, StatusEffect, StatusEffectData, StatusEffectKind, StatusEffectOf,
+        StatusEffectTimer, StatusEffects, StatusModifiers,
+    };
+    pub(crate) use super::wander::{Wander, WanderTimer};
     pub(crate) use super::{Character, CharacterAssets, SpawnCharacter, impl_character_assets};
 }
 
@@ -33,9 +69,9 @@ use bevy_spritesheet_animation::prelude::*;
 use rand::RngExt as _;
 
 use crate::{
-    animations::prelude::*, characters::prelude::*, core::prelude::*, levels::prelude::*,
-    physics::prelude::*, procgen::prelude::*, render::prelude::*, screens::prelude::*,
-    ui::prelude::*, utils::prelude::*,
+    animations::prelude::*, characters::prelude::*, core::prelude::*, images::prelude::*,
+    levels::prelude::*, physics::prelude::*, procgen::prelude::*, render::prelude::*,
+    screens::prelude::*, ui::prelude::*, utils::prelude::*,
 };
 
 pub(super) struct CharactersPlugin;
@@ -43,21 +79,37 @@ impl Plugin for CharactersPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SpriteAnimations<Slime>>();
         app.init_resource::<SpriteAnimations<Player>>();
+        app.init_resource::<SpriteAnimations<Villager>>();
+        // NOTE: Loot is y-sorted by its sprite center instead of a cel.
+        app.init_resource::<CelSize<Loot>>();
 
         app.add_message::<Attack>();
         app.add_message::<InitAttack>();
 
+        app.add_systems(
+            Startup,
+            (setup_rng::<loot::LootRng>, setup_rng::<wander::WanderRng>),
+        );
+        app.add_systems(
+            OnEnter(Screen::Gameplay),
+            (seed_rng::<loot::LootRng>, seed_rng::<wander::WanderRng>)
+                .after(EnterGameplaySystems::LoadSave)
+                .before(EnterGameplaySystems::Levels),
+        );
+
         app.add_systems(
             Update,
             (
-                (
-                    nav::find_path::<OverworldProcGen>,
-                    nav::refresh_path::<OverworldProcGen>,
-                )
-                    .run_if(in_state(DespawnProcGen(false))),
+                (nav::find_path::<Overworld>, nav::refresh_path::<Overworld>).run_if(
+                    in_state(ProcGenInit(true))
+                        .and(in_state(ActiveLevel::Overworld))
+                        .and(in_state(DespawnProcGen(false))),
+                ),
+                (nav::find_path::<Interior>, nav::refresh_path::<Interior>)
+                    .run_if(in_state(ActiveLevel::Interior)),
                 nav::apply_path.in_set(PausableSystems),
             )
-                .run_if(in_state(ProcGenInit(true)).and(in_state(Screen::Gameplay)))
+                .run_if(in_state(Screen::Gameplay))
                 .chain()
                 .in_set(AppSystems::Update),
         );
@@ -67,15 +119,80 @@ impl Plugin for CharactersPlugin {
                 player::on_init_attack,
                 movement::update_facing_direction,
                 attack::on_melee_attack::<Player>,
+                projectile::on_ranged_attack::<Player>,
             )
                 .run_if(in_state(Screen::Gameplay))
                 .chain(),
         );
+        app.add_systems(
+            Update,
+            (
+                npc::init_npc_attacks::<Slime>,
+                npc::on_init_attack,
+                attack::on_melee_attack::<Slime>,
+                projectile::on_ranged_attack::<Slime>,
+            )
+                .run_if(in_state(Screen::Gameplay))
+                .in_set(AppSystems::Update)
+                .in_set(PausableSystems)
+                .chain(),
+        );
+        app.add_systems(
+            Update,
+            (
+                projectile::move_projectiles,
+                projectile::hit_projectiles,
+                projectile::despawn_expired_projectiles,
+            )
+                .run_if(in_state(Screen::Gameplay))
+                .in_set(AppSystems::Update)
+                .in_set(PausableSystems)
+                .chain(),
+        );
+        app.add_systems(
+            Update,
+            (
+                equipment::apply_equipment_modifiers::<Player>,
+                experience::apply_experience_modifiers::<Player>,
+                health::regen_health.in_set(PausableSystems),
+                hit::apply_knockback.in_set(PausableSystems),
+                movement::apply_dash.in_set(PausableSystems),
+                (
+                    loot::collect_loot.in_set(PausableSystems),
+                    loot::bob_loot,
+                    loot::update_loot_texts,
+                ),
+                wander::wander.in_set(PausableSystems),
+                (
+                    status::apply_mist_effects,
+                    status::update_status_effects,
+                    status::despawn_expired_status_effects,
+                )
+                    .chain()
+                    .in_set(PausableSystems),
+                hit::flash_hit_sprites,
+                (
+                    boss::update_boss_phases::<Slime, Overworld>,
+                    boss::update_boss_fight::<Slime, Overworld>,
+                )
+                    .in_set(PausableSystems),
+            )
+                .run_if(in_state(Screen::Gameplay))
+                .in_set(AppSystems::Update),
+        );
         app.add_systems(
             Update,
             (
                 tick_component_timers::<attack::AttackTimer>,
+                tick_component_timers::<hit::HitStunTimer>,
+                tick_component_timers::<hit::InvulnerabilityTimer>,
+                tick_component_timers::<loot::LootText>,
+                tick_component_timers::<movement::DashCooldownTimer>,
+                tick_component_timers::<movement::DashTimer>,
                 tick_component_timers::<movement::JumpTimer>,
+                tick_component_timers::<projectile::ProjectileTimer>,
+                tick_component_timers::<status::StatusEffectTimer>,
+                tick_component_timers::<wander::WanderTimer>,
             )
                 .in_set(AppSystems::TickTimers),
         );
@@ -83,15 +200,27 @@ impl Plugin for CharactersPlugin {
             PostUpdate,
             (
                 remove_oneshot_component_timers::<attack::AttackTimer>,
+                remove_oneshot_component_timers::<hit::HitStunTimer>,
+                remove_oneshot_component_timers::<hit::InvulnerabilityTimer>,
+                remove_oneshot_component_timers::<movement::DashCooldownTimer>,
+                remove_oneshot_component_timers::<movement::DashTimer>,
                 remove_oneshot_component_timers::<movement::JumpTimer>,
             ),
         );
 
+        app.add_observer(archetype::on_died_split::<Slime, Overworld>);
+        app.add_observer(archetype::on_died_split::<Slime, Interior>);
         app.add_observer(attack::on_delay_attack);
+        app.add_observer(experience::on_gain_experience::<Player>);
         app.add_observer(health::on_damage);
         app.add_observer(nav::on_stop_nav);
+        app.add_observer(player::on_player_died);
+        app.add_observer(status::on_apply_status_effect);
         app.add_observer(on_spawn_character::<Player, Overworld>);
+        app.add_observer(on_spawn_character::<Player, Interior>);
         app.add_observer(on_spawn_character::<Slime, Overworld>);
+        app.add_observer(on_spawn_character::<Slime, Interior>);
+        app.add_observer(on_spawn_character::<Villager, Overworld>);
     }
 }
 
@@ -110,6 +239,7 @@ macro_rules! impl_character_assets {
                     AnimationAction::Idle => &self.idle_sounds,
                     AnimationAction::Walk => &self.walk_sounds,
                     AnimationAction::Jump => &self.jump_sounds,
+                    AnimationAction::Dash => &self.dash_sounds,
                 }
             }
         }
@@ -131,6 +261,25 @@ where
         )
     }
 
+    /// Bundle from `archetype` that overrides parts of [`Character::container_bundle`].
+    fn archetype_bundle(archetype: &Archetype, attack_data: &AttackDataCache<Self>) -> impl Bundle
+    where
+        Self: Visible,
+    {
+        (
+            Health::new(archetype.health),
+            WalkSpeed(archetype.walk_speed),
+            AttackStats::new(
+                archetype.attacks(&attack_data.attacks),
+                attack_data.damage_factor,
+            ),
+            archetype.behavior.clone(),
+            archetype.nav_range(),
+            archetype.tint(),
+            ExperienceReward(archetype.experience),
+        )
+    }
+
     fn collider(shape: String, width: f32, height: f32) -> Collider {
         match shape.as_str() {
             "ball" => Collider::ball(width / 2.),
@@ -166,6 +315,12 @@ where
 {
     pub(crate) entity: Entity,
     pub(crate) pos: Vec2,
+    /// Current [`Health`] to override the default with.
+    pub(crate) health: Option<f32>,
+    /// Maximum [`Health`] to override the default with.
+    ///
+    /// Current [`Health`] is set to this unless it is overridden as well.
+    pub(crate) max_health: Option<f32>,
     pub(crate) _phantom: PhantomData<(T, A)>,
 }
 
@@ -174,8 +329,14 @@ fn on_spawn_character<T, A>(
     event: On<SpawnCharacter<T, A>>,
     mut animation_rng: Single<&mut WyRand, With<AnimationRng>>,
     level: Single<Entity, With<A>>,
+    variant_query: Query<&DisplayVariant>,
+    archetype_query: Query<&ArchetypeIndex>,
     mut commands: Commands,
     sprite_animations: Res<SpriteAnimations<T>>,
+    variants: Option<Res<DisplayVariants<T>>>,
+    archetypes: Option<Res<ArchetypeDataCache<T>>>,
+    loot: Option<Res<LootDataCache<T>>>,
+    attack_data: Res<AttackDataCache<T>>,
     collision_data: Res<CollisionDataCache<T>>,
     shadow: Res<MeshLightShadow<T>>,
 ) where
@@ -189,12 +350,21 @@ fn on_spawn_character<T, A>(
         collision_data.height,
         collision_data.y_offset,
     );
+    // NOTE: Individuals with a `DisplayVariant` use its images instead of the shared `DisplayLayers`.
+    let layers = variant_query
+        .get(event.entity)
+        .ok()
+        .zip(variants.as_deref())
+        .and_then(|(variant, variants)| variants.get(variant.0));
 
     let entity = commands
         .entity(event.entity)
         .insert((
             T::container_bundle(event.pos, animation_delay, -collider_y_offset),
             T::collider(collider_shape, collider_width, collider_height),
+            AttackStats::new(attack_data.attacks.clone(), attack_data.damage_factor),
+            attack_data.hit_reaction,
+            StatusModifiers::default(),
             WorldUiAnchor::HealthBar(Vec2::new(0., collider_height * 2.)),
             children![T::shadow_bundle(&shadow)],
         ))
@@ -204,17 +374,68 @@ fn on_spawn_character<T, A>(
                 Transform::from_xyz(0., -collider_y_offset, 0.),
                 AnimationBase,
             ));
+            if let Some(layers) = layers {
+                animation.insert(Sprite {
+                    image: layers.base.clone(),
+                    ..sprite_animations.base.sprite.clone()
+                });
+            }
             if let Some(floating) = &sprite_animations.floating {
                 animation.with_children(|commands| {
-                    commands.spawn((
+                    let mut animation = commands.spawn((
                         T::animation_bundle(floating),
                         Transform::from_xyz(0., 0., LAYER_Z_DELTA),
                     ));
+                    if let Some(image) = layers.and_then(|l| l.floating.clone()) {
+                        animation.insert(Sprite {
+                            image,
+                            ..floating.sprite.clone()
+                        });
+                    }
                 });
             }
         })
         .id();
 
+    // Add the `LootTable` of `T` if there is one
+    if let Some(loot) = loot {
+        commands
+            .entity(entity)
+            .insert(LootTable(loot.drops.clone()));
+    }
+
+    // Override default stats with the `Archetype` of `entity` if there is one
+    if let Some(archetype) = archetype_query
+        .get(event.entity)
+        .ok()
+        .zip(archetypes.as_deref())
+        .and_then(|(index, archetypes)| archetypes.archetypes.get(index.0))
+    {
+        let scale = archetype.scale.unwrap_or(1.);
+        commands
+            .entity(entity)
+            .insert(T::archetype_bundle(archetype, &attack_data))
+            .entry::<Transform>()
+            .and_modify(move |mut transform| transform.scale = Vec3::new(scale, scale, 1.));
+        if !archetype.phases.is_empty() {
+            commands.entity(entity).insert(Boss::default());
+        }
+    }
+
+    // Override default health
+    if let Some(max) = event.max_health {
+        commands
+            .entity(entity)
+            .entry::<Health>()
+            .and_modify(move |mut health| *health = Health::new(max));
+    }
+    if let Some(current) = event.health {
+        commands
+            .entity(entity)
+            .entry::<Health>()
+            .and_modify(move |mut health| health.current = current);
+    }
+
     // Add `entity` to level so that level handles despawning
     commands.entity(*level).add_child(entity);
 }
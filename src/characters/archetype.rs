@@ -0,0 +1,231 @@
+//! Archetypes that vary stats, appearance and behavior of [`Character`]s of the same type.
+
+use std::{f32::consts::TAU, marker::PhantomData};
+
+use bevy::prelude::*;
+use bevy_prng::WyRand;
+use rand::RngExt as _;
+use serde::Deserialize;
+
+use crate::{characters::prelude::*, levels::prelude::*, log::prelude::*, render::prelude::*};
+
+/// Distance in pixels from the origin that [`ArchetypeBehavior::Splitter`] spawns characters at.
+const SPLIT_DISTANCE: f32 = 8.;
+
+/// Behavior of an [`Archetype`].
+#[derive(Component, Deserialize, Clone, Debug, Default, PartialEq)]
+pub(crate) enum ArchetypeBehavior {
+    /// Navigate to the [`NavTarget`] and attack in melee range.
+    #[default]
+    Chaser,
+    /// [`ArchetypeBehavior::Chaser`] that spawns `count` characters of the [`Archetype`] named `into` on death.
+    Splitter { into: String, count: usize },
+    /// Stop at `range` in pixels to the [`NavTarget`] and attack with [`Projectile`]s from there.
+    Spitter { range: f32 },
+}
+impl ArchetypeBehavior {
+    /// [`NavRange`] at which to stop navigating to the [`NavTarget`].
+    pub(crate) fn nav_range(&self) -> NavRange {
+        match self {
+            Self::Spitter { range } => NavRange(*range),
+            _ => NavRange::default(),
+        }
+    }
+}
+
+/// Archetype deserialized from a ron file.
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct Archetype {
+    pub(crate) name: String,
+    /// Relative chance of being chosen by procgen.
+    ///
+    /// Archetypes with a weight of zero are only spawned by [`ArchetypeBehavior::Splitter`].
+    pub(crate) weight: u32,
+    pub(crate) health: f32,
+    /// Speed in pixels per second.
+    pub(crate) walk_speed: f32,
+    /// [`ExperienceReward`] for killing this.
+    #[serde(default)]
+    pub(crate) experience: f32,
+    /// Names of [`AttackKind`]s that this uses. All [`AttackKind`]s are used if this is empty.
+    #[serde(default)]
+    pub(crate) attacks: Vec<String>,
+    /// Factor that the [`Transform`] is scaled with.
+    #[serde(default)]
+    pub(crate) scale: Option<f32>,
+    /// Index into the color palette that [`Sprite`]s are tinted with.
+    #[serde(default)]
+    pub(crate) tint: Option<usize>,
+    #[serde(default)]
+    pub(crate) behavior: ArchetypeBehavior,
+    /// [`BossPhase`]s in order of decreasing [`BossPhase::health_fraction`].
+    ///
+    /// Archetypes with phases are spawned as [`Boss`].
+    #[serde(default)]
+    pub(crate) phases: Vec<BossPhase>,
+    /// Path of the music that is played during a [`BossFight`].
+    #[serde(default)]
+    pub(crate) music: Option<String>,
+    #[serde(skip)]
+    pub(crate) music_handle: Option<Handle<AudioSource>>,
+}
+impl Archetype {
+    /// [`AttackKind`]s of `attacks` that are named in [`Archetype::attacks`].
+    pub(crate) fn attacks(&self, attacks: &[AttackKind]) -> Vec<AttackKind> {
+        filter_attacks(&self.attacks, attacks)
+    }
+
+    /// [`Tint`] from [`Archetype::tint`].
+    pub(crate) fn tint(&self) -> Tint {
+        Tint(self.tint.and_then(palette_color).unwrap_or(Color::WHITE))
+    }
+
+    /// [`NavRange`] from [`Archetype::behavior`].
+    pub(crate) fn nav_range(&self) -> NavRange {
+        self.behavior.nav_range()
+    }
+}
+
+/// [`AttackKind`]s of `attacks` that are named in `names`. All are returned if `names` is empty.
+pub(crate) fn filter_attacks(names: &[String], attacks: &[AttackKind]) -> Vec<AttackKind> {
+    attacks
+        .iter()
+        .filter(|a| names.is_empty() || names.contains(&a.name))
+        .cloned()
+        .collect()
+}
+
+/// Archetype data deserialized from a ron file.
+#[derive(Deserialize, Asset, TypePath, Default)]
+pub(crate) struct ArchetypeData<T>
+where
+    T: Visible,
+{
+    #[serde(default)]
+    pub(crate) archetypes: Vec<Archetype>,
+    #[serde(skip)]
+    pub(crate) _phantom: PhantomData<T>,
+}
+
+/// Handle for [`ArchetypeData`].
+#[derive(Resource)]
+pub(crate) struct ArchetypeHandle<T>(pub(crate) Handle<ArchetypeData<T>>)
+where
+    T: Visible;
+
+/// Cache for [`ArchetypeData`]
+///
+/// This is to allow easier access.
+#[derive(Resource, Default)]
+pub(crate) struct ArchetypeDataCache<T>
+where
+    T: Visible,
+{
+    pub(crate) archetypes: Vec<Archetype>,
+    pub(crate) _phantom: PhantomData<T>,
+}
+impl<T> ArchetypeDataCache<T>
+where
+    T: Visible,
+{
+    /// Index of the [`Archetype`] with `name`.
+    pub(crate) fn index(&self, name: &str) -> Option<usize> {
+        self.archetypes.iter().position(|a| a.name == name)
+    }
+
+    /// Index of the first [`Archetype`] with [`Archetype::phases`].
+    pub(crate) fn boss(&self) -> Option<usize> {
+        self.archetypes.iter().position(|a| !a.phases.is_empty())
+    }
+
+    /// Index of a random [`Archetype`] weighted by [`Archetype::weight`].
+    pub(crate) fn choose(&self, rng: &mut WyRand) -> Option<usize> {
+        let total: u32 = self.archetypes.iter().map(|a| a.weight).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut roll = rng.random_range(0..total);
+        self.archetypes.iter().position(|a| {
+            if roll < a.weight {
+                return true;
+            }
+            roll -= a.weight;
+            false
+        })
+    }
+}
+
+/// Index into [`ArchetypeDataCache`] that overrides the default [`Character::container_bundle`].
+#[derive(Component, Clone, Copy, Debug, Reflect, Deref)]
+pub(crate) struct ArchetypeIndex(pub(crate) usize);
+
+/// [`Color`] that [`Sprite`]s are tinted with unless another tint takes precedence.
+#[derive(Component, Clone, Copy, Debug)]
+pub(crate) struct Tint(pub(crate) Color);
+
+/// Distance in pixels to the [`NavTarget`] at which a [`Navigator`] stops approaching.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub(crate) struct NavRange(pub(crate) f32);
+
+/// On [`CharacterDied`], spawn the characters of [`ArchetypeBehavior::Splitter`].
+///
+/// Characters are only spawned if the dead character is part of the level `A`.
+pub(super) fn on_died_split<T, A>(
+    event: On<CharacterDied>,
+    query: Query<(&Transform, &ArchetypeBehavior, &ChildOf), With<T>>,
+    level_query: Query<(), With<A>>,
+    mut commands: Commands,
+    archetypes: Res<ArchetypeDataCache<T>>,
+) where
+    T: Character + Visible,
+    A: Level,
+{
+    let Ok((transform, behavior, child_of)) = query.get(event.0) else {
+        return;
+    };
+    let ArchetypeBehavior::Splitter { into, count } = behavior else {
+        return;
+    };
+    if !level_query.contains(child_of.parent()) {
+        return;
+    }
+    let Some(index) = archetypes.index(into) else {
+        warn_once!("{}", WARN_UNKNOWN_ARCHETYPE);
+        return;
+    };
+
+    spawn_archetypes::<T, A>(
+        &mut commands,
+        index,
+        *count,
+        transform.translation.xy(),
+        SPLIT_DISTANCE,
+    );
+}
+
+/// Spawn `count` characters of the [`Archetype`] at `index` evenly spread at `distance` around `pos`.
+///
+/// In the overworld, these are recorded by procgen so that they persist with their chunk and in save files.
+/// In other levels, they are transient.
+pub(crate) fn spawn_archetypes<T, A>(
+    commands: &mut Commands,
+    index: usize,
+    count: usize,
+    pos: Vec2,
+    distance: f32,
+) where
+    T: Character + Visible,
+    A: Level,
+{
+    for i in 0..count {
+        let offset = Vec2::from_angle(TAU * i as f32 / count as f32) * distance;
+        let entity = commands.spawn((T::default(), ArchetypeIndex(index))).id();
+        commands.trigger(SpawnCharacter::<T, A> {
+            entity,
+            pos: pos + offset,
+            health: None,
+            max_health: None,
+            _phantom: PhantomData,
+        });
+    }
+}
@@ -1,7 +1,16 @@
+use std::marker::PhantomData;
+
 use bevy::prelude::*;
+use bevy_enoki::prelude::*;
+use bevy_prng::WyRand;
 use bevy_rapier2d::{parry::shape, prelude::*};
+use rand::seq::IndexedRandom as _;
+use serde::Deserialize;
 
-use crate::{characters::prelude::*, log::prelude::*, physics::prelude::*, render::prelude::*};
+use crate::{
+    animations::prelude::*, audio::prelude::*, characters::prelude::*, log::prelude::*,
+    physics::prelude::*, render::prelude::*,
+};
 
 /// Direction the [`Character`] is aiming.
 #[derive(Component, Deref, DerefMut)]
@@ -12,24 +21,124 @@ impl Default for AimDirection {
     }
 }
 
-/// Relevant data for an attack.
-pub(crate) struct AttackData {
-    pub(crate) _name: &'static str,
+/// Kind of attack deserialized from a ron file.
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct AttackKind {
+    pub(crate) name: String,
     pub(crate) damage: f32,
     /// Attack range in pixels.
     ///
     /// First value is width, second is height.
-    pub(crate) range: Vec2,
+    /// For [`Attack::Ranged`], this is the size of the [`Projectile`].
+    pub(crate) range: (f32, f32),
     /// Cooldown in seconds after attack is done
     pub(crate) cooldown_secs: f32,
+    /// Distance in pixels that targets are pushed away.
+    #[serde(default)]
+    pub(crate) knockback: f32,
+    /// Path of the particle effect. Falls back to the default particle of the attack if this is [`None`].
+    #[serde(default)]
+    pub(crate) particle: Option<String>,
+    /// Paths of sounds of which a random one is played on attack.
+    #[serde(default)]
+    pub(crate) sounds: Vec<String>,
+    /// [`AnimationAction`] that is played on attack if the attacker is idle.
+    #[serde(default)]
+    pub(crate) animation: Option<AnimationAction>,
+    /// Projectile data that is only used for [`Attack::Ranged`].
+    #[serde(default)]
+    pub(crate) projectile: Option<ProjectileData>,
+    /// [`StatusEffectData`] that is applied to targets on hit.
+    #[serde(default)]
+    pub(crate) effects: Vec<StatusEffectData>,
+    #[serde(skip)]
+    pub(crate) particle_handle: Option<Handle<Particle2dEffect>>,
+    #[serde(skip)]
+    pub(crate) sound_handles: Vec<Handle<AudioSource>>,
+}
+impl AttackKind {
+    /// Attack range in pixels as [`Vec2`].
+    pub(crate) fn range(&self) -> Vec2 {
+        Vec2::new(self.range.0, self.range.1)
+    }
+
+    /// Whether this is an [`Attack::Ranged`].
+    pub(crate) fn is_ranged(&self) -> bool {
+        self.projectile.is_some()
+    }
+
+    /// Play a random sound and the [`AnimationAction`] of this if `state` is idle.
+    ///
+    /// [`AnimationAction::Jump`] also inserts [`JumpTimer`] to return to idle.
+    pub(crate) fn play_effects(
+        &self,
+        commands: &mut Commands,
+        rng: &mut WyRand,
+        entity: Entity,
+        state: &mut AnimationState,
+    ) {
+        if let Some(sound) = self.sound_handles.choose(rng) {
+            commands.spawn(sound_effect(sound.clone()));
+        }
+
+        let Some(action) = self.animation else {
+            return;
+        };
+        if state.0.0 != AnimationAction::Idle {
+            return;
+        }
+        if action == AnimationAction::Jump {
+            // NOTE: Using try here is necessary since the entity might have been despawned elsewhere.
+            commands.entity(entity).try_insert(JumpTimer::default());
+        }
+        state.set_new_action(action);
+    }
+}
+
+/// Attack data deserialized from a ron file.
+#[derive(Deserialize, Asset, TypePath, Default)]
+pub(crate) struct AttackData<T>
+where
+    T: Visible,
+{
+    #[serde(default)]
+    pub(crate) damage_factor: Option<f32>,
+    #[serde(default)]
+    pub(crate) attacks: Vec<AttackKind>,
+    /// Seconds of hit-stun after taking [`Damage`].
+    #[serde(default)]
+    pub(crate) hit_stun_secs: Option<f32>,
+    /// Seconds of invulnerability after taking [`Damage`].
+    #[serde(default)]
+    pub(crate) invulnerability_secs: Option<f32>,
+    #[serde(skip)]
+    pub(crate) _phantom: PhantomData<T>,
+}
+
+/// Handle for [`AttackData`].
+#[derive(Resource)]
+pub(crate) struct AttackHandle<T>(pub(crate) Handle<AttackData<T>>)
+where
+    T: Visible;
+
+/// Cache for [`AttackData`]
+///
+/// This is to allow easier access.
+#[derive(Resource, Default)]
+pub(crate) struct AttackDataCache<T>
+where
+    T: Visible,
+{
+    pub(crate) damage_factor: f32,
+    pub(crate) attacks: Vec<AttackKind>,
+    pub(crate) hit_reaction: HitReaction,
+    pub(crate) _phantom: PhantomData<T>,
 }
 
 /// [`Message`] that is written if the source [`Entity`] has attacked.
 #[derive(Message)]
 pub(crate) enum Attack {
     Melee(Entity),
-    // TODO: Implement this.
-    #[allow(dead_code)]
     Ranged(Entity),
 }
 impl From<&InitAttack> for Attack {
@@ -47,10 +156,16 @@ impl From<&InitAttack> for Attack {
 #[derive(Message)]
 pub(crate) enum InitAttack {
     Melee(Entity),
-    // TODO: Implement this.
-    #[allow(dead_code)]
     Ranged(Entity),
 }
+impl InitAttack {
+    /// Source [`Entity`] of the attack.
+    pub(crate) fn entity(&self) -> Entity {
+        match self {
+            Self::Melee(entity) | Self::Ranged(entity) => *entity,
+        }
+    }
+}
 
 /// [`EntityEvent`] that is triggered if the contained [`Entity`]'s next [`Attack`] should be delayed.
 #[derive(EntityEvent)]
@@ -60,12 +175,51 @@ pub(crate) struct DelayAttack {
 }
 
 /// Stats for [`Attack`]
+///
+/// The current melee and ranged attacks are stored as indexes into `attacks`.
 #[derive(Component, Default)]
 pub(crate) struct AttackStats {
-    pub(crate) _attacks: Vec<AttackData>,
+    pub(crate) attacks: Vec<AttackKind>,
     pub(crate) damage_factor: f32,
-    pub(crate) melee: Option<AttackData>,
-    pub(crate) _ranged: Option<AttackData>,
+    melee: Option<usize>,
+    ranged: Option<usize>,
+}
+impl AttackStats {
+    /// [`AttackStats`] starting with the first melee and ranged attack in `attacks`.
+    pub(crate) fn new(attacks: Vec<AttackKind>, damage_factor: f32) -> Self {
+        Self {
+            melee: Self::next(&attacks, None, false),
+            ranged: Self::next(&attacks, None, true),
+            attacks,
+            damage_factor,
+        }
+    }
+
+    /// Current [`Attack::Melee`].
+    pub(crate) fn melee(&self) -> Option<&AttackKind> {
+        self.attacks.get(self.melee?)
+    }
+
+    /// Current [`Attack::Ranged`].
+    pub(crate) fn ranged(&self) -> Option<&AttackKind> {
+        self.attacks.get(self.ranged?)
+    }
+
+    /// Cycle the current melee and ranged attacks to the next ones of the same kind.
+    pub(crate) fn cycle(&mut self) {
+        self.melee = Self::next(&self.attacks, self.melee, false);
+        self.ranged = Self::next(&self.attacks, self.ranged, true);
+    }
+
+    /// Index of the next attack after `current` in `attacks` that matches `ranged`.
+    ///
+    /// This wraps around to the start of `attacks`.
+    fn next(attacks: &[AttackKind], current: Option<usize>, ranged: bool) -> Option<usize> {
+        let start = current.map_or(0, |i| i + 1);
+        (0..attacks.len())
+            .map(|i| (start + i) % attacks.len())
+            .find(|i| attacks[*i].is_ranged() == ranged)
+    }
 }
 
 /// Timer that tracks [`Attack`]s
@@ -73,21 +227,23 @@ pub(crate) struct AttackStats {
 #[reflect(Component)]
 pub(crate) struct AttackTimer(pub(crate) Timer);
 
-/// Simple punch [`Attack`] with short range
-pub(crate) fn punch() -> AttackData {
-    AttackData {
-        _name: "punch",
-        damage: 1.,
-        range: Vec2::new(8., 16.),
-        cooldown_secs: 0.5,
-    }
-}
-
 /// On [`Attack::Melee`], trigger [`Damage`] on [Entity]s within range.
+///
+/// Only characters of the opposing side are hit, so [`Npc`]s only hit non-[`Npc`]s and vice versa.
 pub(super) fn on_melee_attack<T>(
     mut reader: MessageReader<Attack>,
-    target_query: Query<&Health>,
-    origin_query: Query<(&Transform, &AimDirection, &AttackStats), With<T>>,
+    mut rng: Single<&mut WyRand, With<AnimationRng>>,
+    target_query: Query<Has<Npc>, With<Health>>,
+    mut origin_query: Query<
+        (
+            &Transform,
+            &AimDirection,
+            &AttackStats,
+            &mut AnimationState,
+            Has<Npc>,
+        ),
+        With<T>,
+    >,
     mut commands: Commands,
     collision_data: Res<CollisionDataCache<T>>,
     rapier_context: ReadRapierContext,
@@ -101,15 +257,22 @@ pub(super) fn on_melee_attack<T>(
         };
 
         let rapier_context = rapier_context.single().expect(ERR_INVALID_RAPIER_CONTEXT);
-        let (transform, direction, stats) = origin_query.get(*entity).expect(ERR_INVALID_ATTACKER);
-        let Some(melee) = &stats.melee else {
+        // NOTE: Attacks of other characters are handled by their own systems.
+        let Ok((transform, direction, stats, mut state, is_npc)) = origin_query.get_mut(*entity)
+        else {
+            continue;
+        };
+        let Some(melee) = stats.melee() else {
             warn_once!("{}", WARN_INVALID_ATTACK_DATA);
-            return;
+            continue;
         };
 
         // Cast ray to determine boundary of `Collider`
+        // NOTE: `CollisionDataCache` is unscaled, so the `Archetype` scale has to be applied.
         // NOTE: We have to add an offset to max_toi to ensure that the ray reaches the boundary.
-        let max_toi = (collision_data.width / 2.).max(collision_data.height / 2.) + 1.;
+        let scale = transform.scale.xy();
+        let max_toi =
+            (collision_data.width / 2.).max(collision_data.height / 2.) * scale.max_element() + 1.;
         // Filter for the source itself
         let filter = &|e| e == *entity;
         let filter = QueryFilter::exclude_dynamic()
@@ -118,11 +281,12 @@ pub(super) fn on_melee_attack<T>(
         let pos = transform.translation.xy();
         let Some((_, extent)) = rapier_context.cast_ray(pos, direction.0, max_toi, false, filter)
         else {
-            return;
+            continue;
         };
 
         // Collect all entities within attack range
-        let shape_half_size = melee.range / 2.;
+        // NOTE: `extent` is already in world space since the ray hits the scaled `Collider`.
+        let shape_half_size = melee.range() * scale / 2.;
         let offset = direction.0 * (extent + shape_half_size.x);
         let shape_pos = pos + offset;
         let shape_rot = direction.0.to_angle();
@@ -133,20 +297,34 @@ pub(super) fn on_melee_attack<T>(
             .exclude_rigid_body(*entity);
         let mut targets = Vec::new();
         rapier_context.intersect_shape(shape_pos, shape_rot, &shape, filter, |e| {
-            if target_query.contains(e) {
+            if target_query
+                .get(e)
+                .is_ok_and(|target_is_npc| target_is_npc != is_npc)
+            {
                 targets.push(e);
             }
             true
         });
 
         // Apply attack
-        let damage = stats.damage_factor * melee.damage;
-        commands.trigger(Damage { targets, damage });
+        commands.trigger(Damage {
+            targets,
+            damage: stats.damage_factor * melee.damage,
+            direction: direction.0,
+            knockback: melee.knockback,
+            effects: melee.effects.clone(),
+            source: DamageSource::Attack(*entity),
+        });
         commands.trigger(SpawnChildParticleOnce::<MeleeParticle>::new(
             *entity,
-            offset.extend(Y_SORT_OVERRIDE_Z_DELTA),
-            particle.handle.clone(),
+            // NOTE: The particle is a child of `entity`, so the offset is scaled with it.
+            (offset / scale).extend(Y_SORT_OVERRIDE_Z_DELTA),
+            melee
+                .particle_handle
+                .clone()
+                .unwrap_or_else(|| particle.handle.clone()),
         ));
+        melee.play_effects(&mut commands, &mut rng, *entity, &mut state);
     }
 }
 
@@ -0,0 +1,197 @@
+//! Bosses with phases that are fought in an arena.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::{audio::prelude::*, characters::prelude::*, levels::prelude::*, log::prelude::*};
+
+/// Distance in pixels between the [`Player`] and a [`Boss`] at which a [`BossFight`] starts.
+const BOSS_FIGHT_START_DISTANCE: f32 = 96.;
+
+/// Distance in pixels between the [`Player`] and the [`Boss`] at which a [`BossFight`] ends.
+const BOSS_FIGHT_END_DISTANCE: f32 = 320.;
+
+/// Distance in chunks around [`BossFight::arena`] in which chunks are not despawned.
+const BOSS_ARENA_DISTANCE: u32 = 1;
+
+/// Distance in pixels from the [`Boss`] that adds are spawned at.
+const BOSS_ADD_DISTANCE: f32 = 24.;
+
+/// Phase of a boss [`Archetype`] that is entered once at a health threshold.
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct BossPhase {
+    /// [`Health::fraction`] at or below which this phase is entered.
+    pub(crate) health_fraction: f32,
+    /// Speed in pixels per second.
+    pub(crate) walk_speed: f32,
+    /// Names of [`AttackKind`]s that this uses. All [`AttackKind`]s are used if this is empty.
+    #[serde(default)]
+    pub(crate) attacks: Vec<String>,
+    #[serde(default)]
+    pub(crate) behavior: ArchetypeBehavior,
+    /// Name of the [`Archetype`] of adds that are spawned on entering this phase.
+    #[serde(default)]
+    pub(crate) adds: Option<String>,
+    #[serde(default)]
+    pub(crate) add_count: usize,
+}
+
+/// Boss with [`Archetype::phases`].
+///
+/// Contains the number of [`BossPhase`]s that have been entered.
+#[derive(Component, Default, Reflect)]
+pub(crate) struct Boss(pub(crate) usize);
+
+/// Ongoing fight against a [`Boss`].
+///
+/// Chunks around `arena` are not despawned while this exists.
+#[derive(Resource)]
+pub(crate) struct BossFight {
+    pub(crate) boss: Entity,
+    /// Position where the fight started.
+    pub(crate) arena: Vec2,
+}
+impl BossFight {
+    /// Whether the chunk at `chunk_pos` is within [`BOSS_ARENA_DISTANCE`] of the arena.
+    pub(crate) fn contains_chunk(&self, chunk_pos: IVec2, chunk_size_px: Vec2) -> bool {
+        let arena_chunk_pos = (self.arena / chunk_size_px).floor().as_ivec2();
+        arena_chunk_pos.chebyshev_distance(chunk_pos) <= BOSS_ARENA_DISTANCE
+    }
+}
+
+/// Marker for music that is played during a [`BossFight`].
+#[derive(Component)]
+pub(super) struct BossMusic;
+
+/// Enter [`BossPhase`]s of [`Boss`]es whose [`Health`] dropped below the threshold.
+///
+/// Adds are only spawned for bosses that are part of the level `A` and not for phases that
+/// have already been passed on spawn, e.g. after loading.
+pub(super) fn update_boss_phases<T, A>(
+    query: Query<
+        (
+            &mut Boss,
+            &Health,
+            &ArchetypeIndex,
+            &Transform,
+            &ChildOf,
+            &mut WalkSpeed,
+            &mut AttackStats,
+            &mut ArchetypeBehavior,
+            &mut NavRange,
+        ),
+        (With<T>, Changed<Health>),
+    >,
+    level_query: Query<(), With<A>>,
+    mut commands: Commands,
+    archetypes: Res<ArchetypeDataCache<T>>,
+    attack_data: Res<AttackDataCache<T>>,
+) where
+    T: Character + Visible,
+    A: Level,
+{
+    for (
+        mut boss,
+        health,
+        index,
+        transform,
+        child_of,
+        mut walk_speed,
+        mut stats,
+        mut behavior,
+        mut nav_range,
+    ) in query
+    {
+        if !level_query.contains(child_of.parent()) {
+            continue;
+        }
+        let Some(archetype) = archetypes.archetypes.get(index.0) else {
+            continue;
+        };
+        let spawn_adds = !boss.is_added();
+
+        while let Some(phase) = archetype.phases.get(boss.0)
+            && health.fraction() <= phase.health_fraction
+        {
+            boss.0 += 1;
+            walk_speed.0 = phase.walk_speed;
+            *stats = AttackStats::new(
+                filter_attacks(&phase.attacks, &attack_data.attacks),
+                attack_data.damage_factor,
+            );
+            *behavior = phase.behavior.clone();
+            *nav_range = phase.behavior.nav_range();
+
+            let Some(adds) = phase.adds.as_ref().filter(|_| spawn_adds) else {
+                continue;
+            };
+            let Some(index) = archetypes.index(adds) else {
+                warn_once!("{}", WARN_UNKNOWN_ARCHETYPE);
+                continue;
+            };
+            spawn_archetypes::<T, A>(
+                &mut commands,
+                index,
+                phase.add_count,
+                transform.translation.xy(),
+                BOSS_ADD_DISTANCE,
+            );
+        }
+    }
+}
+
+/// Start a [`BossFight`] if the [`Player`] approaches a [`Boss`] and end it if they leave.
+///
+/// During a fight, [`Archetype::music`] replaces other [`Music`]. The fight ends if the [`Boss`] is despawned.
+pub(super) fn update_boss_fight<T, A>(
+    player: Single<&Transform, With<Player>>,
+    boss_query: Query<(Entity, &Transform, &ArchetypeIndex), (With<T>, With<Boss>)>,
+    music_query: Query<Entity, With<Music>>,
+    boss_music_query: Query<Entity, With<BossMusic>>,
+    level: Single<Entity, With<A>>,
+    mut commands: Commands,
+    archetypes: Res<ArchetypeDataCache<T>>,
+    fight: Option<Res<BossFight>>,
+) where
+    T: Character + Visible,
+    A: Level,
+{
+    let player_pos = player.translation.xy();
+
+    if let Some(fight) = fight {
+        let ongoing = boss_query.get(fight.boss).is_ok_and(|(_, transform, _)| {
+            transform.translation.xy().distance(player_pos) <= BOSS_FIGHT_END_DISTANCE
+        });
+        if !ongoing {
+            commands.remove_resource::<BossFight>();
+            for entity in boss_music_query {
+                commands.entity(entity).despawn();
+            }
+        }
+        return;
+    }
+
+    let Some((boss, transform, index)) = boss_query.iter().find(|(_, transform, _)| {
+        transform.translation.xy().distance(player_pos) <= BOSS_FIGHT_START_DISTANCE
+    }) else {
+        return;
+    };
+    commands.insert_resource(BossFight {
+        boss,
+        arena: transform.translation.xy(),
+    });
+
+    let Some(handle) = archetypes
+        .archetypes
+        .get(index.0)
+        .and_then(|a| a.music_handle.clone())
+    else {
+        return;
+    };
+    for entity in music_query {
+        commands.entity(entity).despawn();
+    }
+    commands
+        .entity(*level)
+        .with_child((Name::new("Boss Music"), BossMusic, music(handle)));
+}
@@ -0,0 +1,217 @@
+//! Inventory and equipment that modify [`AttackStats`] and [`Health`].
+
+use std::marker::PhantomData;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use num_enum::TryFromPrimitive;
+use serde::Deserialize;
+
+use crate::{characters::prelude::*, render::prelude::*};
+
+/// Slot that an [`Item`] is equipped in.
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect, TryFromPrimitive)]
+#[repr(u8)]
+pub(crate) enum EquipmentSlot {
+    Head,
+    Back,
+    Hands,
+    Feet,
+}
+impl EquipmentSlot {
+    pub(crate) const HEAD: u8 = Self::Head as u8;
+    pub(crate) const BACK: u8 = Self::Back as u8;
+    pub(crate) const HANDS: u8 = Self::Hands as u8;
+    pub(crate) const FEET: u8 = Self::Feet as u8;
+
+    /// All slots in the order that their layers are drawn from top to bottom.
+    pub(crate) const ALL: [Self; 4] = [Self::Head, Self::Back, Self::Hands, Self::Feet];
+
+    /// Whether [`Item`]s in this slot are drawn on the floating layer of [`DisplayLayers`].
+    pub(crate) fn floating(self) -> bool {
+        self == Self::Hands
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Head => "Head",
+            Self::Back => "Back",
+            Self::Hands => "Hands",
+            Self::Feet => "Feet",
+        }
+    }
+}
+
+/// Item deserialized from a ron file.
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct Item {
+    pub(crate) name: String,
+    pub(crate) slot: EquipmentSlot,
+    /// Path of the image layer that is drawn while this is equipped.
+    pub(crate) image: String,
+    /// Factor that [`AttackStats::damage_factor`] is multiplied with while this is equipped.
+    #[serde(default)]
+    pub(crate) damage_factor: Option<f32>,
+    /// [`Health::max`] that is added while this is equipped.
+    #[serde(default)]
+    pub(crate) max_health: f32,
+    /// Whether this is in the [`Inventory`] of a new game.
+    #[serde(default)]
+    pub(crate) starting: bool,
+    #[serde(skip)]
+    pub(crate) image_handle: Handle<Image>,
+}
+
+/// Item data deserialized from a ron file.
+#[derive(Deserialize, Asset, TypePath, Default)]
+pub(crate) struct ItemData<T>
+where
+    T: Visible,
+{
+    #[serde(default)]
+    pub(crate) items: Vec<Item>,
+    #[serde(skip)]
+    pub(crate) _phantom: PhantomData<T>,
+}
+
+/// Handle for [`ItemData`].
+#[derive(Resource)]
+pub(crate) struct ItemHandle<T>(pub(crate) Handle<ItemData<T>>)
+where
+    T: Visible;
+
+/// Cache for [`ItemData`]
+///
+/// This is to allow easier access.
+#[derive(Resource, Default)]
+pub(crate) struct ItemDataCache<T>
+where
+    T: Visible,
+{
+    pub(crate) items: Vec<Item>,
+    pub(crate) _phantom: PhantomData<T>,
+}
+impl<T> ItemDataCache<T>
+where
+    T: Visible,
+{
+    /// Index of the [`Item`] with `name`.
+    pub(crate) fn index(&self, name: &str) -> Option<usize> {
+        self.items.iter().position(|i| i.name == name)
+    }
+
+    /// [`Inventory`] of a new game.
+    pub(crate) fn starting_inventory(&self) -> Inventory {
+        Inventory(
+            self.items
+                .iter()
+                .enumerate()
+                .filter(|(_, i)| i.starting)
+                .map(|(index, _)| index)
+                .collect(),
+        )
+    }
+}
+
+/// Owned [`Item`]s stored as indexes into [`ItemDataCache::items`].
+#[derive(Component, Default, Debug, Deref, DerefMut)]
+pub(crate) struct Inventory(pub(crate) Vec<usize>);
+
+/// Equipped [`Item`]s stored as indexes into [`ItemDataCache::items`].
+#[derive(Component, Default, Debug)]
+pub(crate) struct Equipment {
+    pub(crate) slots: HashMap<EquipmentSlot, usize>,
+    /// [`Health::max`] that has been added by equipped [`Item`]s.
+    pub(crate) applied_max_health: f32,
+}
+impl Equipment {
+    /// Equip the next [`Item`] of `slot` in `inventory` after the current one.
+    ///
+    /// This wraps around to an empty slot after the last [`Item`].
+    pub(crate) fn cycle(&mut self, slot: EquipmentSlot, inventory: &Inventory, items: &[Item]) {
+        let owned: Vec<_> = inventory
+            .iter()
+            .copied()
+            .filter(|i| items.get(*i).is_some_and(|i| i.slot == slot))
+            .collect();
+        let next = match self.slots.get(&slot) {
+            Some(current) => owned
+                .iter()
+                .position(|i| i == current)
+                .and_then(|p| owned.get(p + 1)),
+            None => owned.first(),
+        };
+        match next {
+            Some(next) => self.slots.insert(slot, *next),
+            None => self.slots.remove(&slot),
+        };
+    }
+
+    /// Equipped [`Item`]s in the order that their layers are drawn from top to bottom.
+    pub(crate) fn items<'a>(&self, items: &'a [Item]) -> impl Iterator<Item = &'a Item> {
+        EquipmentSlot::ALL
+            .iter()
+            .filter_map(|s| self.slots.get(s))
+            .filter_map(|i| items.get(*i))
+    }
+
+    /// Product of [`Item::damage_factor`] of all equipped [`Item`]s.
+    pub(crate) fn damage_factor(&self, items: &[Item]) -> f32 {
+        self.items(items)
+            .map(|i| i.damage_factor.unwrap_or(1.))
+            .product()
+    }
+
+    /// Sum of [`Item::max_health`] of all equipped [`Item`]s.
+    pub(crate) fn max_health(&self, items: &[Item]) -> f32 {
+        self.items(items).map(|i| i.max_health).sum()
+    }
+}
+
+/// Apply [`Equipment`] to [`AttackStats::damage_factor`] and [`Health::max`].
+///
+/// [`AttackStats::damage_factor`] also includes the increase of the [`Experience`] level
+/// and [`StatusModifiers::damage_factor`].
+/// [`AttackStats`] is reinserted when a [`Character`] is respawned, so changes of it are handled as well.
+pub(super) fn apply_equipment_modifiers<T>(
+    query: Query<
+        (
+            &mut Equipment,
+            &mut AttackStats,
+            &mut Health,
+            Option<&Experience>,
+            Option<&StatusModifiers>,
+        ),
+        (
+            With<T>,
+            Or<(
+                Changed<Equipment>,
+                Changed<AttackStats>,
+                Changed<Experience>,
+                Changed<StatusModifiers>,
+            )>,
+        ),
+    >,
+    attack_data: Res<AttackDataCache<T>>,
+    item_data: Res<ItemDataCache<T>>,
+    progression: Res<ProgressionDataCache<T>>,
+) where
+    T: Character + Visible,
+{
+    for (mut equipment, mut stats, mut health, experience, modifiers) in query {
+        let damage_factor = attack_data.damage_factor
+            * equipment.damage_factor(&item_data.items)
+            * experience.map_or(1., |e| progression.damage_factor(e.level))
+            * modifiers.map_or(1., |m| m.damage_factor);
+        if stats.damage_factor != damage_factor {
+            stats.damage_factor = damage_factor;
+        }
+
+        let max_health = equipment.max_health(&item_data.items);
+        if equipment.applied_max_health != max_health {
+            let max = health.max - equipment.applied_max_health + max_health;
+            health.set_max(max);
+            // NOTE: This does not change the equipped items, so it should not trigger change detection.
+            equipment.bypass_change_detection().applied_max_health = max_health;
+        }
+    }
+}
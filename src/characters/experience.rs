@@ -0,0 +1,189 @@
+//! Experience and levelling that increase stats of characters.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use bevy_prng::WyRand;
+use rand::seq::IndexedRandom as _;
+use serde::Deserialize;
+
+use crate::{
+    animations::prelude::*, audio::prelude::*, characters::prelude::*, render::prelude::*,
+};
+
+/// Stat increases that are applied on reaching a level.
+#[derive(Deserialize, Clone, Copy, Debug, Default)]
+pub(crate) struct LevelUp {
+    /// Total [`Experience::points`] that are required to reach this level.
+    pub(crate) experience: f32,
+    /// Increase of [`Health::max`].
+    #[serde(default)]
+    pub(crate) max_health: f32,
+    /// Increase of [`WalkSpeed`] in pixels per second.
+    #[serde(default)]
+    pub(crate) walk_speed: f32,
+    /// Increase of [`AttackStats::damage_factor`].
+    #[serde(default)]
+    pub(crate) damage_factor: f32,
+}
+
+/// Progression data deserialized from a ron file.
+#[derive(Deserialize, Asset, TypePath, Default)]
+pub(crate) struct ProgressionData<T>
+where
+    T: Visible,
+{
+    /// [`LevelUp`]s in order of the levels that they reach.
+    #[serde(default)]
+    pub(crate) levels: Vec<LevelUp>,
+    /// Paths of sounds of which a random one is played on level up.
+    #[serde(default)]
+    pub(crate) sounds: Vec<String>,
+    #[serde(skip)]
+    pub(crate) _phantom: PhantomData<T>,
+}
+
+/// Handle for [`ProgressionData`].
+#[derive(Resource)]
+pub(crate) struct ProgressionHandle<T>(pub(crate) Handle<ProgressionData<T>>)
+where
+    T: Visible;
+
+/// Cache for [`ProgressionData`]
+///
+/// This is to allow easier access.
+#[derive(Resource, Default)]
+pub(crate) struct ProgressionDataCache<T>
+where
+    T: Visible,
+{
+    pub(crate) levels: Vec<LevelUp>,
+    pub(crate) sound_handles: Vec<Handle<AudioSource>>,
+    pub(crate) _phantom: PhantomData<T>,
+}
+impl<T> ProgressionDataCache<T>
+where
+    T: Visible,
+{
+    /// [`LevelUp`]s that have been reached at `level`.
+    fn reached(&self, level: usize) -> impl Iterator<Item = &LevelUp> {
+        self.levels.iter().take(level)
+    }
+
+    /// Total increase of [`WalkSpeed`] at `level`.
+    pub(crate) fn walk_speed(&self, level: usize) -> f32 {
+        self.reached(level).map(|l| l.walk_speed).sum()
+    }
+
+    /// Factor of [`AttackStats::damage_factor`] at `level`.
+    pub(crate) fn damage_factor(&self, level: usize) -> f32 {
+        1. + self.reached(level).map(|l| l.damage_factor).sum::<f32>()
+    }
+
+    /// Fraction of the progress from the current to the next level.
+    ///
+    /// Returns `1.` if there is no next level.
+    pub(crate) fn fraction(&self, experience: &Experience) -> f32 {
+        let Some(next) = self.levels.get(experience.level) else {
+            return 1.;
+        };
+        let current = experience
+            .level
+            .checked_sub(1)
+            .and_then(|i| self.levels.get(i))
+            .map_or(0., |l| l.experience);
+        let required = next.experience - current;
+        if required > 0. {
+            ((experience.points - current) / required).clamp(0., 1.)
+        } else {
+            1.
+        }
+    }
+}
+
+/// Experience that is gained from [`ExperienceReward`]s and that determines the level.
+///
+/// `level` is the number of [`LevelUp`]s that have been reached.
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub(crate) struct Experience {
+    pub(crate) level: usize,
+    pub(crate) points: f32,
+    /// Increase of [`WalkSpeed`] that has been applied.
+    ///
+    /// This is reset if [`WalkSpeed`] is reinserted, e.g. on respawn.
+    pub(crate) applied_walk_speed: f32,
+}
+
+/// [`Experience::points`] that are rewarded for killing this.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub(crate) struct ExperienceReward(pub(crate) f32);
+
+/// [`EntityEvent`] that adds `amount` to the [`Experience`] of `entity`.
+#[derive(EntityEvent)]
+pub(crate) struct GainExperience {
+    pub(crate) entity: Entity,
+    pub(crate) amount: f32,
+}
+
+/// On [`GainExperience`], add [`Experience::points`] and apply reached [`LevelUp`]s.
+///
+/// [`LevelUp::max_health`] is applied immediately, while other stats are applied by
+/// [`apply_experience_modifiers`] and together with [`Equipment`].
+/// Each level up plays a [`LevelUpParticle`] and a random sound.
+pub(super) fn on_gain_experience<T>(
+    event: On<GainExperience>,
+    mut query: Query<(&mut Experience, &mut Health), With<T>>,
+    mut rng: Single<&mut WyRand, With<AnimationRng>>,
+    mut commands: Commands,
+    particle: Res<ParticleHandle<LevelUpParticle>>,
+    progression: Res<ProgressionDataCache<T>>,
+) where
+    T: Visible,
+{
+    let Ok((mut experience, mut health)) = query.get_mut(event.entity) else {
+        return;
+    };
+    experience.points += event.amount;
+
+    while let Some(level_up) = progression.levels.get(experience.level)
+        && experience.points >= level_up.experience
+    {
+        experience.level += 1;
+        health.upgrade(level_up.max_health);
+
+        commands.trigger(SpawnChildParticleOnce::<LevelUpParticle>::new(
+            event.entity,
+            Vec3::new(0., 0., Y_SORT_OVERRIDE_Z_DELTA),
+            particle.handle.clone(),
+        ));
+        if let Some(sound) = progression.sound_handles.choose(&mut rng) {
+            commands.spawn(sound_effect(sound.clone()));
+        }
+    }
+}
+
+/// Apply [`Experience`] to [`WalkSpeed`].
+pub(super) fn apply_experience_modifiers<T>(
+    query: Query<
+        (&mut Experience, &mut WalkSpeed),
+        (With<T>, Or<(Changed<Experience>, Changed<WalkSpeed>)>),
+    >,
+    progression: Res<ProgressionDataCache<T>>,
+) where
+    T: Visible,
+{
+    for (mut experience, mut walk_speed) in query {
+        // NOTE: Changes of `WalkSpeed` by this are not detected, so it has been reinserted at its base value.
+        if walk_speed.is_changed() {
+            experience.bypass_change_detection().applied_walk_speed = 0.;
+        }
+
+        let bonus = progression.walk_speed(experience.level);
+        if experience.applied_walk_speed != bonus {
+            walk_speed.bypass_change_detection().0 += bonus - experience.applied_walk_speed;
+            experience.bypass_change_detection().applied_walk_speed = bonus;
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
+use bevy_prng::WyRand;
 
-use crate::render::prelude::*;
+use crate::{characters::prelude::*, render::prelude::*};
 
 /// Health that determines if a [`Component`] should be despawned.
 #[derive(Component, Reflect)]
@@ -23,42 +24,164 @@ impl Health {
     pub(crate) fn is_alive(&self) -> bool {
         self.current > 0.
     }
+    /// Increase `current` by `amount` up to `max`.
+    pub(crate) fn heal(&mut self, amount: f32) {
+        self.current = (self.current + amount).min(self.max);
+    }
+    /// Increase `max` and `current` by `amount`.
+    pub(crate) fn upgrade(&mut self, amount: f32) {
+        self.max += amount;
+        self.current += amount;
+    }
+    /// Set `max` and limit `current` to it.
+    pub(crate) fn set_max(&mut self, max: f32) {
+        self.max = max;
+        self.current = self.current.min(max);
+    }
 }
 
+/// Passive [`Health`] regeneration per second.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub(crate) struct HealthRegen(pub(crate) f32);
+
 /// Apply damage to [`Health`].
 #[derive(Event)]
 pub(crate) struct Damage {
     pub(crate) targets: Vec<Entity>,
     pub(crate) damage: f32,
+    /// Direction in which targets are pushed.
+    pub(crate) direction: Vec2,
+    /// Distance in pixels that targets are pushed.
+    pub(crate) knockback: f32,
+    /// [`StatusEffectData`] that is applied to surviving targets.
+    pub(crate) effects: Vec<StatusEffectData>,
+    pub(crate) source: DamageSource,
+}
+
+/// Source of [`Damage`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum DamageSource {
+    /// [`Attack`] of the contained [`Entity`], including its [`Projectile`]s.
+    Attack(Entity),
+    /// [`StatusEffect`] that deals damage over time and was applied by the contained [`Entity`].
+    ///
+    /// This ignores [`InvulnerabilityTimer`] and does not trigger [`HitReaction`] or [`BloodParticle`]s.
+    Status(Option<Entity>),
+}
+impl DamageSource {
+    /// [`Entity`] that is rewarded for kills.
+    fn entity(self) -> Option<Entity> {
+        match self {
+            Self::Attack(entity) => Some(entity),
+            Self::Status(entity) => entity,
+        }
+    }
 }
 
+/// [`EntityEvent`] that is triggered if the [`Health`] of anything but the [`Player`] has reached zero.
+///
+/// This is triggered before the contained [`Entity`] is despawned.
+#[derive(EntityEvent)]
+pub(crate) struct CharacterDied(pub(crate) Entity);
+
+/// [`EntityEvent`] that is triggered if the [`Player`]'s [`Health`] has reached zero.
+#[derive(EntityEvent)]
+pub(crate) struct PlayerDied(pub(crate) Entity);
+
 /// Apply [`Damage`] to [`Health`] and handle particles and despawning.
+///
+/// The [`Player`] is not despawned, instead [`PlayerDied`] is triggered.
+/// Anything else triggers [`CharacterDied`] before it is despawned, rewards its [`ExperienceReward`]
+/// to the [`DamageSource`] with [`GainExperience`] and drops [`Loot`] from its [`LootTable`].
+/// Targets with [`InvulnerabilityTimer`] are ignored and surviving targets react with [`HitReaction`]
+/// and receive [`Damage::effects`], unless the [`DamageSource`] is [`DamageSource::Status`].
 pub(super) fn on_damage(
     event: On<Damage>,
-    mut target_query: Query<(&mut Health, &Transform)>,
+    mut target_query: Query<(
+        &mut Health,
+        &Transform,
+        Option<&HitReaction>,
+        Option<&ExperienceReward>,
+        Option<&LootTable>,
+        Option<&ChildOf>,
+        Has<InvulnerabilityTimer>,
+    )>,
+    player_query: Query<(), With<Player>>,
+    mut loot_rng: Single<&mut WyRand, With<LootRng>>,
     mut commands: Commands,
     blood_particle: Res<ParticleHandle<BloodParticle>>,
     death_particle: Res<ParticleHandle<DeathParticle>>,
 ) {
     for entity in &event.targets {
-        let Ok((mut health, transform)) = target_query.get_mut(*entity) else {
+        let Ok((mut health, transform, reaction, reward, loot, child_of, invulnerable)) =
+            target_query.get_mut(*entity)
+        else {
             continue;
         };
+        let is_status = matches!(event.source, DamageSource::Status(_));
+        if !health.is_alive() || (invulnerable && !is_status) {
+            continue;
+        }
 
         health.current -= event.damage;
-        if health.is_alive() {
-            commands.trigger(SpawnChildParticleOnce::<BloodParticle>::new(
-                *entity,
-                Vec3::new(0., 0., -Y_SORT_OVERRIDE_Z_DELTA),
-                blood_particle.handle.clone(),
-            ));
-        } else {
-            // NOTE: Using try here is necessary since the entity might have been despawned elsewhere.
-            commands.entity(*entity).try_despawn();
+        if !health.is_alive() {
+            if player_query.contains(*entity) {
+                commands.trigger(PlayerDied(*entity));
+            } else {
+                commands.trigger(CharacterDied(*entity));
+                if let Some(source) = event.source.entity()
+                    && let Some(reward) = reward
+                {
+                    commands.trigger(GainExperience {
+                        entity: source,
+                        amount: reward.0,
+                    });
+                }
+                if let Some(loot) = loot
+                    && let Some(child_of) = child_of
+                {
+                    // NOTE: Loot is added to the level of `entity` so that the level handles despawning.
+                    let drops = loot.roll(&mut commands, &mut loot_rng, transform.translation.xy());
+                    commands.entity(child_of.parent()).add_children(&drops);
+                }
+                // NOTE: Using try here is necessary since the entity might have been despawned elsewhere.
+                commands.entity(*entity).try_despawn();
+            }
             commands.trigger(SpawnParticleOnce::<DeathParticle>::new(
                 transform.translation.xy().extend(OVERLAY_Z),
                 death_particle.handle.clone(),
             ));
+            continue;
+        }
+
+        let DamageSource::Attack(source) = event.source else {
+            continue;
+        };
+        commands.trigger(SpawnChildParticleOnce::<BloodParticle>::new(
+            *entity,
+            Vec3::new(0., 0., -Y_SORT_OVERRIDE_Z_DELTA),
+            blood_particle.handle.clone(),
+        ));
+        if let Some(reaction) = reaction {
+            reaction.apply(&mut commands, *entity, event.direction, event.knockback);
+        }
+        for effect in &event.effects {
+            commands.trigger(ApplyStatusEffect {
+                entity: *entity,
+                source: Some(source),
+                effect: effect.clone(),
+            });
+        }
+    }
+}
+
+/// Apply [`HealthRegen`] to [`Health`] of living entities.
+pub(super) fn regen_health(query: Query<(&mut Health, &HealthRegen)>, time: Res<Time>) {
+    for (mut health, regen) in query {
+        // NOTE: This avoids triggering change detection for entities at full health.
+        if health.is_alive() && health.current < health.max {
+            health.heal(regen.0 * time.delta_secs());
         }
     }
 }
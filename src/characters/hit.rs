@@ -0,0 +1,136 @@
+//! Reactions to [`Damage`] like knockback, hit-stun and invulnerability.
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::{animations::prelude::*, characters::prelude::*, log::prelude::*};
+
+/// Color that [`Sprite`]s are tinted with during [`HitStunTimer`].
+const HIT_FLASH_COLOR: Color = Color::srgb(1., 0.4, 0.4);
+
+/// Durations of reactions on [`Damage`].
+#[derive(Component, Default, Clone, Copy, Debug)]
+pub(crate) struct HitReaction {
+    /// Seconds during which the [`Character`] can not move or attack.
+    pub(crate) stun_secs: f32,
+    /// Seconds during which the [`Character`] ignores further [`Damage`].
+    pub(crate) invulnerability_secs: f32,
+}
+impl HitReaction {
+    /// Insert [`HitStunTimer`], [`InvulnerabilityTimer`] and [`Knockback`] for `entity`.
+    ///
+    /// `knockback` is the distance in pixels `entity` is pushed along `direction` during hit-stun.
+    pub(crate) fn apply(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        direction: Vec2,
+        knockback: f32,
+    ) {
+        // NOTE: Using try here is necessary since the entity might have been despawned elsewhere.
+        if self.invulnerability_secs > 0. {
+            commands
+                .entity(entity)
+                .try_insert(InvulnerabilityTimer(Timer::from_seconds(
+                    self.invulnerability_secs,
+                    TimerMode::Once,
+                )));
+        }
+        if self.stun_secs > 0. {
+            commands.entity(entity).try_insert((
+                HitStunTimer(Timer::from_seconds(self.stun_secs, TimerMode::Once)),
+                Knockback(direction.normalize_or_zero() * knockback / self.stun_secs),
+            ));
+        }
+    }
+}
+
+/// Timer that tracks hit-stun.
+///
+/// The [`Character`] can not move or attack while this exists.
+#[derive(Component, Debug, Clone, PartialEq, Reflect, Deref, DerefMut)]
+#[reflect(Component)]
+pub(crate) struct HitStunTimer(pub(crate) Timer);
+
+/// Timer that tracks invulnerability.
+///
+/// The [`Character`] ignores [`Damage`] while this exists.
+#[derive(Component, Debug, Clone, PartialEq, Reflect, Deref, DerefMut)]
+#[reflect(Component)]
+pub(crate) struct InvulnerabilityTimer(pub(crate) Timer);
+
+/// Velocity in pixels per second that is applied during [`HitStunTimer`].
+#[derive(Component, Default, Debug)]
+pub(crate) struct Knockback(pub(crate) Vec2);
+
+/// Move [`Knockback`] targets through their [`KinematicCharacterController`].
+///
+/// [`Knockback`] is removed after [`HitStunTimer`] has finished.
+pub(super) fn apply_knockback(
+    query: Query<(
+        Entity,
+        &mut KinematicCharacterController,
+        &Knockback,
+        &HitStunTimer,
+    )>,
+    mut commands: Commands,
+    time: Res<Time>,
+) {
+    for (entity, mut controller, knockback, timer) in query {
+        if timer.0.is_finished() {
+            // NOTE: Using try here is necessary since the entity might have been despawned elsewhere.
+            commands.entity(entity).try_remove::<Knockback>();
+            controller.translation = Some(Vec2::ZERO);
+            continue;
+        }
+        controller.translation = Some(knockback.0 * time.delta_secs());
+    }
+}
+
+/// Tint the [`AnimationBase`] [`Sprite`] of [`Character`]s and its floating layer with
+/// [`HIT_FLASH_COLOR`] during [`HitStunTimer`].
+///
+/// Falls back to [`StatusModifiers::tint`] and then [`Tint`] otherwise.
+pub(super) fn flash_hit_sprites(
+    mut removed: RemovedComponents<HitStunTimer>,
+    changed_query: Query<
+        Entity,
+        Or<(Added<HitStunTimer>, Changed<StatusModifiers>, Changed<Tint>)>,
+    >,
+    character_query: Query<(
+        Option<&StatusModifiers>,
+        Option<&Tint>,
+        Has<HitStunTimer>,
+        &Children,
+    )>,
+    mut base_query: Query<(&mut Sprite, Option<&Children>), With<AnimationBase>>,
+    mut floating_query: Query<&mut Sprite, Without<AnimationBase>>,
+) {
+    for entity in changed_query.iter().chain(removed.read()) {
+        let Ok((modifiers, tint, stunned, children)) = character_query.get(entity) else {
+            continue;
+        };
+        let color = if stunned {
+            HIT_FLASH_COLOR
+        } else {
+            modifiers
+                .and_then(|m| m.tint)
+                .or(tint.map(|t| t.0))
+                .unwrap_or(Color::WHITE)
+        };
+
+        let child = children
+            .iter()
+            .find(|e| base_query.contains(*e))
+            .expect(ERR_INVALID_CHILDREN);
+        let (mut base_sprite, children) = base_query.get_mut(child).expect(ERR_INVALID_CHILDREN);
+        base_sprite.color = color;
+
+        if let Some(children) = children
+            && let Some(child) = children.iter().find(|e| floating_query.contains(*e))
+            && let Ok(mut floating_sprite) = floating_query.get_mut(child)
+        {
+            floating_sprite.color = color;
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//! Loot that is dropped by characters on death and collected by the [`Player`] on contact.
+
+use std::{f32::consts::TAU, marker::PhantomData};
+
+use bevy::prelude::*;
+use bevy_prng::WyRand;
+use bevy_rapier2d::prelude::*;
+use rand::{RngExt as _, seq::IndexedRandom as _};
+use serde::Deserialize;
+
+use crate::{
+    audio::prelude::*, characters::prelude::*, levels::prelude::*, log::prelude::*,
+    procgen::prelude::*, render::prelude::*, ui::prelude::*, utils::prelude::*,
+};
+
+/// Size of a [`Loot`] sprite in px.
+const LOOT_SIZE: Vec2 = Vec2::new(6., 6.);
+
+/// Maximum distance in px from the dead character that [`Loot`] is scattered within.
+const LOOT_SCATTER_DISTANCE: f32 = 8.;
+
+/// Height in px that [`Loot`] bobs up and down by.
+const LOOT_BOB_HEIGHT: f32 = 2.;
+
+/// Angular speed in radians per second that [`Loot`] bobs with.
+const LOOT_BOB_SPEED: f32 = 4.;
+
+/// Duration in seconds that a [`LootText`] is displayed for.
+const LOOT_TEXT_SECS: f32 = 1.;
+
+/// Speed in px per second that a [`LootText`] rises with.
+const LOOT_TEXT_RISE_SPEED: f32 = 16.;
+
+/// Font size of a [`LootText`].
+const LOOT_TEXT_FONT_SIZE: f32 = 8.;
+
+/// Color of a [`LootKind::Item`] sprite.
+const ITEM_COLOR: Color = Color::srgb(0.35, 0.6, 0.95);
+
+/// Effect of [`Loot`] on the [`Player`].
+///
+/// Health drops are [`PickupKind`]s and collected like [`Pickup`]s.
+#[derive(Component, Deserialize, Clone, Debug)]
+pub(crate) enum LootKind {
+    /// Add the [`Item`] with the contained name to the [`Inventory`].
+    Item(String),
+}
+impl LootKind {
+    /// Text that is displayed as [`LootText`] on collection.
+    fn text(&self) -> String {
+        match self {
+            Self::Item(name) => name.clone(),
+        }
+    }
+
+    /// Spawn [`Loot`] at `pos` that bobs with the phase `bob_phase`.
+    pub(crate) fn spawn(&self, commands: &mut Commands, pos: Vec2, bob_phase: f32) -> Entity {
+        commands
+            .spawn((
+                (
+                    Name::new(format!("Loot {}", self.text())),
+                    Loot,
+                    self.clone(),
+                ),
+                // Positioning/Visibility
+                (
+                    Transform::from_translation(pos.extend(FOREGROUND_Z)),
+                    YSort(FOREGROUND_Z),
+                    YSortYOffset(-LOOT_SIZE.y / 2.),
+                    Visibility::Inherited,
+                ),
+                // Physics
+                (
+                    Collider::ball(LOOT_SIZE.max_element() / 2.),
+                    Sensor,
+                    // NOTE: The player is kinematic, so this has to be enabled.
+                    ActiveCollisionTypes::default() | ActiveCollisionTypes::KINEMATIC_STATIC,
+                ),
+                children![(
+                    Sprite::from_color(ITEM_COLOR, LOOT_SIZE),
+                    Transform::default(),
+                    LootBob(bob_phase),
+                )],
+            ))
+            .id()
+    }
+}
+
+/// What is spawned by a [`LootDrop`].
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) enum LootDropKind {
+    /// [`Loot`] with a [`LootKind`].
+    Loot(LootKind),
+    /// [`Loot`] with a [`PickupKind`].
+    ///
+    /// This does not have the [`Pickup`] marker, since it is procedurally generated as [`Loot`].
+    Pickup(PickupKind),
+}
+impl LootDropKind {
+    /// Spawn [`Loot`] at `pos` that bobs with the phase `bob_phase` if it is a [`LootKind`].
+    pub(crate) fn spawn(&self, commands: &mut Commands, pos: Vec2, bob_phase: f32) -> Entity {
+        match self {
+            Self::Loot(kind) => kind.spawn(commands, pos, bob_phase),
+            Self::Pickup(kind) => commands.spawn((Loot, kind.bundle(pos))).id(),
+        }
+    }
+}
+
+/// Possible drop of a [`LootTable`].
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct LootDrop {
+    pub(crate) kind: LootDropKind,
+    /// Chance between `0.` and `1.` that this is dropped.
+    pub(crate) chance: f64,
+}
+
+/// Loot data deserialized from a ron file.
+#[derive(Deserialize, Asset, TypePath, Default)]
+pub(crate) struct LootData<T>
+where
+    T: Visible,
+{
+    #[serde(default)]
+    pub(crate) drops: Vec<LootDrop>,
+    #[serde(skip)]
+    pub(crate) _phantom: PhantomData<T>,
+}
+
+/// Handle for [`LootData`].
+#[derive(Resource)]
+pub(crate) struct LootHandle<T>(pub(crate) Handle<LootData<T>>)
+where
+    T: Visible;
+
+/// Cache for [`LootData`]
+///
+/// This is to allow easier access.
+#[derive(Resource, Default)]
+pub(crate) struct LootDataCache<T>
+where
+    T: Visible,
+{
+    pub(crate) drops: Vec<LootDrop>,
+    pub(crate) _phantom: PhantomData<T>,
+}
+
+/// [`LootDrop`]s that are rolled when this dies.
+#[derive(Component, Clone, Debug, Default)]
+pub(crate) struct LootTable(pub(crate) Vec<LootDrop>);
+impl LootTable {
+    /// Roll every [`LootDrop`] and spawn the dropped [`Loot`] scattered around `pos`.
+    ///
+    /// Returns the spawned entities.
+    pub(crate) fn roll(&self, commands: &mut Commands, rng: &mut WyRand, pos: Vec2) -> Vec<Entity> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, drop)| rng.random_bool(drop.chance.clamp(0., 1.)))
+            .map(|(index, drop)| {
+                let angle = rng.random_range(0.0..TAU);
+                let offset = Vec2::from_angle(angle) * rng.random_range(0.0..LOOT_SCATTER_DISTANCE);
+                let entity = drop.kind.spawn(commands, pos + offset, angle);
+                commands.entity(entity).insert(LootDropIndex(index));
+                entity
+            })
+            .collect()
+    }
+}
+
+/// Loot marker.
+///
+/// This is procedurally generated so that dropped loot is despawned and spawned again with its chunk.
+#[derive(Component, Default, Reflect, Clone)]
+pub(crate) struct Loot;
+impl Visible for Loot {}
+impl ProcGenerated for Loot {}
+
+/// Index of the [`LootDrop`] in [`LootTable`] that this [`Loot`] has been dropped from.
+///
+/// [`LootTable`]s are created from [`LootDataCache`], so this is also an index into its drops.
+#[derive(Component, Clone, Copy, Debug, Deref)]
+pub(crate) struct LootDropIndex(pub(crate) usize);
+
+/// Bobbing of the [`Loot`] sprite.
+///
+/// Contains the phase in radians.
+#[derive(Component)]
+pub(super) struct LootBob(f32);
+
+/// Text that rises above collected [`Loot`] and fades out.
+#[derive(Component, Deref, DerefMut)]
+pub(super) struct LootText(Timer);
+
+/// Rng for rolling [`LootDrop`]s
+#[derive(Component, Default, TypePath)]
+pub(crate) struct LootRng;
+impl ForkedRng for LootRng {}
+
+/// Apply [`LootBob`] to [`Loot`] sprites.
+pub(super) fn bob_loot(query: Query<(&mut Transform, &LootBob)>, time: Res<Time>) {
+    for (mut transform, bob) in query {
+        transform.translation.y =
+            LOOT_BOB_HEIGHT * (time.elapsed_secs() * LOOT_BOB_SPEED + bob.0).sin();
+    }
+}
+
+/// Apply [`LootKind`] to the [`Player`] on contact and despawn the [`Loot`].
+///
+/// Each collection plays a random pickup sound and spawns a [`LootText`].
+/// [`Item`]s that are already in the [`Inventory`] are not collected and stay on the ground.
+/// [`PickupKind`]s are collected with the [`Pickup`]s instead.
+pub(super) fn collect_loot(
+    player: Single<(Entity, &Health, &mut Inventory), With<Player>>,
+    loot_query: Query<(Entity, &Transform, &LootKind, &ChildOf), With<Loot>>,
+    mut rng: Single<&mut WyRand, With<LootRng>>,
+    mut commands: Commands,
+    rapier_context: ReadRapierContext,
+    assets: Res<PlayerAssets>,
+    font: Res<UiFontHandle>,
+    item_data: Res<ItemDataCache<Player>>,
+) {
+    let rapier_context = rapier_context.single().expect(ERR_INVALID_RAPIER_CONTEXT);
+    let (entity, health, mut inventory) = player.into_inner();
+    if !health.is_alive() {
+        return;
+    }
+
+    for (loot, transform, kind, child_of) in &loot_query {
+        if rapier_context.intersection_pair(entity, loot) != Some(true) {
+            continue;
+        }
+
+        let LootKind::Item(name) = kind;
+        match item_data.index(name) {
+            Some(index) if !inventory.contains(&index) => inventory.push(index),
+            Some(_) => continue,
+            None => warn_once!("{}", WARN_UNKNOWN_LOOT_ITEM),
+        }
+
+        if let Some(sound) = assets
+            .pickup_sounds
+            .as_ref()
+            .and_then(|s| s.choose(&mut rng))
+        {
+            commands.spawn(sound_effect(sound.clone()));
+        }
+        let text = commands
+            .spawn((
+                Name::new("Loot Text"),
+                LootText(Timer::from_seconds(LOOT_TEXT_SECS, TimerMode::Once)),
+                Text2d::new(kind.text()),
+                TextFont::from(font.0.clone()).with_font_size(LOOT_TEXT_FONT_SIZE),
+                TextColor(ITEM_COLOR),
+                Transform::from_translation(
+                    (transform.translation.xy() + Vec2::Y * LOOT_SIZE.y).extend(OVERLAY_Z),
+                ),
+            ))
+            .id();
+        commands.entity(child_of.parent()).add_child(text);
+
+        // NOTE: Using try here is necessary since the entity might have been despawned elsewhere.
+        commands.entity(loot).try_despawn();
+    }
+}
+
+/// Move [`LootText`]s up, fade them out and despawn them once finished.
+pub(super) fn update_loot_texts(
+    query: Query<(Entity, &LootText, &mut Transform, &mut TextColor)>,
+    mut commands: Commands,
+    time: Res<Time>,
+) {
+    for (entity, text, mut transform, mut color) in query {
+        if text.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        transform.translation.y += LOOT_TEXT_RISE_SPEED * time.delta_secs();
+        color.0.set_alpha(text.fraction_remaining());
+    }
+}
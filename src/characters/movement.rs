@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
-use crate::characters::prelude::*;
+use crate::{animations::prelude::*, characters::prelude::*};
 
 /// Direction the [`Character`] is facing.
 #[derive(Component)]
@@ -33,6 +33,73 @@ impl Default for JumpTimer {
     }
 }
 
+/// Distance in pixels that a dash moves.
+pub(crate) const DASH_DISTANCE: f32 = 32.;
+
+/// Dashing duration in seconds.
+pub(crate) const DASH_DURATION_SECS: f32 = 0.2;
+
+/// Duration in seconds after the start of a dash before the next dash.
+pub(crate) const DASH_COOLDOWN_SECS: f32 = 0.8;
+
+/// Motion of an ongoing dash along `direction`.
+///
+/// Contains the `distance` in pixels that has already been moved.
+#[derive(Component, Default, Debug)]
+pub(crate) struct DashMotion {
+    pub(crate) direction: Vec2,
+    pub(crate) distance: f32,
+}
+
+/// Timer that tracks dashing
+#[derive(Component, Debug, Clone, PartialEq, Reflect, Deref, DerefMut)]
+#[reflect(Component)]
+pub(crate) struct DashTimer(pub(crate) Timer);
+impl Default for DashTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(DASH_DURATION_SECS, TimerMode::Once))
+    }
+}
+
+/// Timer that tracks the cooldown of dashing
+#[derive(Component, Debug, Clone, PartialEq, Reflect, Deref, DerefMut)]
+#[reflect(Component)]
+pub(crate) struct DashCooldownTimer(pub(crate) Timer);
+impl Default for DashCooldownTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(DASH_COOLDOWN_SECS, TimerMode::Once))
+    }
+}
+
+/// Move [`DashMotion`] targets through their [`KinematicCharacterController`].
+///
+/// The moved distance follows [`EaseFunction::QuadraticOut`] over [`DashTimer`].
+/// [`DashMotion`] and [`AnimationAction::Dash`] end after [`DashTimer`] has finished.
+pub(super) fn apply_dash(
+    query: Query<(
+        Entity,
+        &mut DashMotion,
+        &DashTimer,
+        &mut KinematicCharacterController,
+        &mut AnimationState,
+    )>,
+    mut commands: Commands,
+) {
+    for (entity, mut motion, timer, mut controller, mut animation_state) in query {
+        let distance = DASH_DISTANCE * EaseFunction::QuadraticOut.sample_clamped(timer.fraction());
+        controller.translation = Some(motion.direction * (distance - motion.distance));
+        motion.distance = distance;
+
+        if timer.is_finished() {
+            // NOTE: Using try here is necessary since the entity might have been despawned elsewhere.
+            commands.entity(entity).try_remove::<DashMotion>();
+            if animation_state.0.0 == AnimationAction::Dash {
+                animation_state.set_new_action(AnimationAction::Idle);
+            }
+        }
+    }
+}
+
 /// Update [`FacingDirection`].
 pub(super) fn update_facing_direction(
     query: Query<
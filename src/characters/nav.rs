@@ -3,13 +3,11 @@
  * - https://github.com/vleue/vleue_navigator
  */
 
-use std::ops::Deref;
-
 use bevy::{math::FloatPow, prelude::*};
 use bevy_rapier2d::prelude::*;
 use vleue_navigator::prelude::*;
 
-use crate::{animations::prelude::*, characters::prelude::*, log::prelude::*, procgen::prelude::*};
+use crate::{animations::prelude::*, characters::prelude::*, levels::prelude::*, log::prelude::*};
 
 /// Navigation target
 ///
@@ -35,9 +33,10 @@ pub(crate) struct Path {
 #[derive(EntityEvent)]
 pub(super) struct StopNav(Entity);
 
-/// Find [`Path`] to [`NavTarget`]
-pub(super) fn find_path<T>(
-    navmesh: Single<(&ManagedNavMesh, Ref<NavMeshStatus>)>,
+/// Find [`Path`] to [`NavTarget`] on the [`ManagedNavMesh`] of [`Level`] `L`
+pub(super) fn find_path<L>(
+    navmesh_query: Query<(&ManagedNavMesh, Ref<NavMeshStatus>, &ChildOf)>,
+    level: Single<Entity, With<L>>,
     target_query: Query<(Entity, &Transform, &NavTarget), Without<Navigator>>,
     navigator_query: Query<
         (Entity, &Transform),
@@ -47,13 +46,11 @@ pub(super) fn find_path<T>(
     mut navmeshes: ResMut<Assets<NavMesh>>,
     mut delta: Local<f32>,
 ) where
-    T: ProcGenerated,
+    L: Level,
 {
-    let (navmesh, status) = navmesh.deref();
-    if **status != NavMeshStatus::Built && *delta == 0. {
+    let Some(navmesh) = level_navmesh(&navmesh_query, *level, *delta, &mut navmeshes) else {
         return;
-    }
-    let navmesh = navmeshes.get_mut(*navmesh).expect(ERR_INVALID_NAVMESH);
+    };
 
     // Get target with maximum priority
     let Some((target, target_pos, _)) = target_query.iter().max_by_key(|(_, _, t)| t.0) else {
@@ -85,26 +82,25 @@ pub(super) fn find_path<T>(
     }
 }
 
-/// Refresh [`Path`]
-pub(super) fn refresh_path<T>(
-    navmesh: Single<(&ManagedNavMesh, Ref<NavMeshStatus>)>,
+/// Refresh [`Path`] on the [`ManagedNavMesh`] of [`Level`] `L`
+pub(super) fn refresh_path<L>(
+    navmesh_query: Query<(&ManagedNavMesh, Ref<NavMeshStatus>, &ChildOf)>,
+    level: Single<Entity, With<L>>,
     navigator_query: Query<(Entity, &Transform, &mut Path), With<Navigator>>,
     target_transforms: Query<&Transform, With<NavTarget>>,
     mut commands: Commands,
     mut navmeshes: ResMut<Assets<NavMesh>>,
     mut delta: Local<f32>,
 ) where
-    T: ProcGenerated,
+    L: Level,
 {
     if target_transforms.is_empty() {
         return;
     }
 
-    let (navmesh, status) = navmesh.deref();
-    if **status != NavMeshStatus::Built && *delta == 0. {
+    let Some(navmesh) = level_navmesh(&navmesh_query, *level, *delta, &mut navmeshes) else {
         return;
-    }
-    let navmesh = navmeshes.get_mut(*navmesh).expect(ERR_INVALID_NAVMESH);
+    };
 
     let mut path_found = false;
     for (entity, transform, mut path) in navigator_query {
@@ -131,6 +127,24 @@ pub(super) fn refresh_path<T>(
     }
 }
 
+/// [`NavMesh`] of the [`ManagedNavMesh`] that is a child of `level`.
+///
+/// Returns [`None`] if it is not built yet and no search delta is being applied.
+fn level_navmesh<'a>(
+    navmesh_query: &Query<(&ManagedNavMesh, Ref<NavMeshStatus>, &ChildOf)>,
+    level: Entity,
+    delta: f32,
+    navmeshes: &'a mut Assets<NavMesh>,
+) -> Option<&'a mut NavMesh> {
+    let (navmesh, status, _) = navmesh_query
+        .iter()
+        .find(|(_, _, child_of)| child_of.parent() == level)?;
+    if *status != NavMeshStatus::Built && delta == 0. {
+        return None;
+    }
+    Some(navmeshes.get_mut(navmesh).expect(ERR_INVALID_NAVMESH))
+}
+
 /// Next step for the [`Path`].
 ///
 /// This also validates if `start` is inside of `navmesh`.
@@ -158,6 +172,8 @@ fn next_path_step(
 const PATH_OVERSHOOT_THRESHOLD_DIVISOR: f32 = 50.;
 
 /// Apply [`Path`]
+///
+/// Navigators stop approaching their target within [`NavRange`].
 pub(super) fn apply_path(
     navigator_query: Query<
         (
@@ -168,9 +184,12 @@ pub(super) fn apply_path(
             Option<&KinematicCharacterControllerOutput>,
             &mut Path,
             &WalkSpeed,
+            &StatusModifiers,
+            Option<&NavRange>,
         ),
-        With<Navigator>,
+        (With<Navigator>, Without<HitStunTimer>),
     >,
+    target_query: Query<&Transform, With<NavTarget>>,
     mut commands: Commands,
     time: Res<Time>,
 ) {
@@ -182,11 +201,22 @@ pub(super) fn apply_path(
         controller_output,
         mut path,
         walk_speed,
+        modifiers,
+        range,
     ) in navigator_query
     {
         let navigator_pos = transform.translation.xy();
+        if let Some(range) = range
+            && let Ok(target) = target_query.get(path.target)
+            && navigator_pos.distance(target.translation.xy()) <= range.0
+        {
+            animation_state.set_new_action(AnimationAction::Idle);
+            continue;
+        }
+
         let direction = path.current - navigator_pos;
-        let direction = direction.normalize_or_zero() * walk_speed.0 * time.delta_secs();
+        let direction =
+            direction.normalize_or_zero() * walk_speed.0 * modifiers.walk_speed * time.delta_secs();
         controller.translation = Some(direction);
 
         // If `entity` collided with `path.target` stop applying path and return.
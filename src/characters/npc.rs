@@ -12,7 +12,8 @@ use bevy_asset_loader::prelude::*;
 use bevy_rapier2d::prelude::*;
 
 use crate::{
-    animations::prelude::*, characters::prelude::*, procgen::prelude::*, render::prelude::*,
+    animations::prelude::*, characters::prelude::*, levels::prelude::*, physics::prelude::*,
+    procgen::prelude::*, render::prelude::*,
 };
 
 /// Assets that are serialized from a ron file
@@ -26,9 +27,29 @@ pub(crate) struct SlimeAssets {
 
     #[asset(key = "slime.jump_sounds", collection(typed), optional)]
     pub(crate) jump_sounds: Option<Vec<Handle<AudioSource>>>,
+
+    #[asset(key = "slime.dash_sounds", collection(typed), optional)]
+    pub(crate) dash_sounds: Option<Vec<Handle<AudioSource>>>,
 }
 impl_character_assets!(SlimeAssets);
 
+/// Assets that are serialized from a ron file
+#[derive(AssetCollection, Resource, Default, Reflect)]
+pub(crate) struct VillagerAssets {
+    #[asset(key = "villager.idle_sounds", collection(typed), optional)]
+    pub(crate) idle_sounds: Option<Vec<Handle<AudioSource>>>,
+
+    #[asset(key = "villager.walk_sounds", collection(typed), optional)]
+    pub(crate) walk_sounds: Option<Vec<Handle<AudioSource>>>,
+
+    #[asset(key = "villager.jump_sounds", collection(typed), optional)]
+    pub(crate) jump_sounds: Option<Vec<Handle<AudioSource>>>,
+
+    #[asset(key = "villager.dash_sounds", collection(typed), optional)]
+    pub(crate) dash_sounds: Option<Vec<Handle<AudioSource>>>,
+}
+impl_character_assets!(VillagerAssets);
+
 /// Npc marker.
 #[derive(Component, Default, Reflect)]
 pub(crate) struct Npc;
@@ -63,16 +84,59 @@ impl Character for Slime {
             // Navigation
             Navigator,
             // Attack
+            (Health::new(5.), AimDirection::default()),
+            // Animations
+            (
+                AnimationAudioIndex::default(),
+                AnimationState::default(),
+                LastAnimationAction::default(),
+                AnimationYOffset::default(),
+                AnimationTimer(Timer::from_seconds(animation_delay, TimerMode::Once)),
+            ),
+        )
+    }
+}
+impl ProcGenerated for Slime {
+    fn map_object() -> Option<MapObjectKind> {
+        Some(MapObjectKind::Slime)
+    }
+}
+impl Visible for Slime {}
+
+/// Walk speed of a [`Villager`].
+const VILLAGER_WALK_SPEED: f32 = 30.;
+
+/// Villager marker
+///
+/// Villagers are not hostile and [`Wander`] around where they were spawned.
+/// They have no [`Health`], so they can not be hit.
+#[derive(Component, Default, Reflect)]
+pub(crate) struct Villager;
+impl Character for Villager {
+    fn container_bundle(pos: Vec2, animation_delay: f32, y_offset: f32) -> impl Bundle {
+        (
+            // Identity
+            (Name::new("Villager"), Npc),
+            // Positioning/Visibility
+            (
+                Transform::from_translation(pos.extend(FOREGROUND_Z)),
+                YSort(FOREGROUND_Z),
+                YSortYOffset(y_offset),
+                Visibility::Inherited,
+            ),
+            // Physics
+            (RigidBody::KinematicPositionBased, GravityScale(0.)),
+            // Movement
             (
-                Health::new(5.),
-                AimDirection::default(),
-                AttackStats {
-                    _attacks: vec![punch()],
-                    damage_factor: 1.,
-                    melee: Some(punch()),
-                    _ranged: None,
-                },
+                KinematicCharacterController::default(),
+                LockedAxes::ROTATION_LOCKED,
+                FacingDirection::default(),
+                WalkSpeed(VILLAGER_WALK_SPEED),
+                Wander::new(pos),
+                WanderTimer(Timer::from_seconds(animation_delay, TimerMode::Once)),
             ),
+            // Attack
+            AimDirection::default(),
             // Animations
             (
                 AnimationAudioIndex::default(),
@@ -84,5 +148,84 @@ impl Character for Slime {
         )
     }
 }
-impl ProcGenerated for Slime {}
-impl Visible for Slime {}
+impl ProcGenerated for Villager {}
+impl Visible for Villager {}
+
+/// Write [`InitAttack`] for [`Npc`]s that have collided with or are within melee range of their [`NavTarget`].
+///
+/// [`Npc`]s that are out of melee range use ranged attacks within their [`NavRange`] instead.
+///
+/// This sets [`AimDirection`] towards the [`NavTarget`] and respects [`AttackTimer`].
+pub(super) fn init_npc_attacks<T>(
+    mut writer: MessageWriter<InitAttack>,
+    npc_query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut AimDirection,
+            &AttackStats,
+            Option<&AttackTimer>,
+            Option<&KinematicCharacterControllerOutput>,
+            Option<&NavRange>,
+        ),
+        (With<T>, With<Npc>, Without<HitStunTimer>),
+    >,
+    target_query: Query<(Entity, &Transform, &NavTarget), Without<Npc>>,
+    mut commands: Commands,
+    collision_data: Res<CollisionDataCache<T>>,
+) where
+    T: Character + Visible,
+{
+    // Get target with maximum priority
+    let Some((target, target_transform, _)) = target_query.iter().max_by_key(|(_, _, t)| t.0)
+    else {
+        return;
+    };
+    let target_pos = target_transform.translation.xy();
+    let extent = (collision_data.width / 2.).max(collision_data.height / 2.);
+
+    for (entity, transform, mut aim_direction, stats, timer, controller_output, range) in npc_query
+    {
+        if let Some(timer) = timer
+            && !timer.0.is_finished()
+        {
+            continue;
+        }
+
+        let pos = transform.translation.xy();
+        let distance = pos.distance(target_pos);
+        let collided = controller_output
+            .is_some_and(|output| output.collisions.iter().any(|c| c.entity == target));
+        let (attack, init_attack) = if let Some(melee) = stats.melee()
+            && (collided || distance <= extent + melee.range().x)
+        {
+            (melee, InitAttack::Melee(entity))
+        } else if let Some(ranged) = stats.ranged()
+            && range.is_some_and(|r| distance <= r.0)
+        {
+            (ranged, InitAttack::Ranged(entity))
+        } else {
+            continue;
+        };
+
+        aim_direction.0 = (target_pos - pos).normalize_or_zero();
+        commands.trigger(DelayAttack {
+            entity,
+            cooldown_secs: attack.cooldown_secs,
+        });
+        writer.write(init_attack);
+    }
+}
+
+/// On [`InitAttack`] from an [`Npc`], write [`Attack`].
+pub(super) fn on_init_attack(
+    mut reader: MessageReader<InitAttack>,
+    mut writer: MessageWriter<Attack>,
+    npc_query: Query<(), With<Npc>>,
+) {
+    for attack in reader.read() {
+        if npc_query.contains(attack.entity()) {
+            writer.write(Attack::from(attack));
+        }
+    }
+}
@@ -19,6 +19,9 @@ use crate::{
 /// Walk speed of [`Player`].
 const PLAYER_WALK_SPEED: f32 = 60.;
 
+/// [`HealthRegen`] of [`Player`] per second.
+const PLAYER_HEALTH_REGEN: f32 = 0.1;
+
 /// Assets that are serialized from a ron file
 #[derive(AssetCollection, Resource, Reflect, Default)]
 pub(crate) struct PlayerAssets {
@@ -30,6 +33,12 @@ pub(crate) struct PlayerAssets {
 
     #[asset(key = "male.jump_sounds", collection(typed), optional)]
     pub(crate) jump_sounds: Option<Vec<Handle<AudioSource>>>,
+
+    #[asset(key = "male.dash_sounds", collection(typed), optional)]
+    pub(crate) dash_sounds: Option<Vec<Handle<AudioSource>>>,
+
+    #[asset(key = "male.pickup_sounds", collection(typed), optional)]
+    pub(crate) pickup_sounds: Option<Vec<Handle<AudioSource>>>,
 }
 impl_character_assets!(PlayerAssets);
 
@@ -68,13 +77,8 @@ impl Character for Player {
             // Attack
             (
                 Health::new(10.),
+                HealthRegen(PLAYER_HEALTH_REGEN),
                 AimDirection::default(),
-                AttackStats {
-                    _attacks: vec![punch()],
-                    damage_factor: 1.,
-                    melee: Some(punch()),
-                    _ranged: None,
-                },
             ),
             // Animations
             (
@@ -89,15 +93,27 @@ impl Character for Player {
 }
 impl Visible for Player {}
 
-/// On [`InitAttack`], set [`AimDirection`] and write [`Attack`].
+/// On [`InitAttack`] from [`Player`], set [`AimDirection`] and write [`Attack`].
 pub(super) fn on_init_attack(
     mut reader: MessageReader<InitAttack>,
     mut writer: MessageWriter<Attack>,
     aim: Single<&mut Action<Aim>, Changed<Action<Aim>>>,
-    mut aim_direction: Single<&mut AimDirection, With<Player>>,
+    player: Single<(Entity, &mut AimDirection), With<Player>>,
 ) {
+    let (entity, mut aim_direction) = player.into_inner();
     for attack in reader.read() {
-        ***aim_direction = ***aim;
+        if attack.entity() != entity {
+            continue;
+        }
+        **aim_direction = ***aim;
         writer.write(Attack::from(attack));
     }
 }
+
+/// On [`PlayerDied`], hide the [`Player`].
+///
+/// The [`Player`] is shown again when it is respawned.
+pub(super) fn on_player_died(event: On<PlayerDied>, mut commands: Commands) {
+    // NOTE: Using try here is necessary since the entity might have been despawned elsewhere.
+    commands.entity(event.0).try_insert(Visibility::Hidden);
+}
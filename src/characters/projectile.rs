@@ -0,0 +1,209 @@
+//! Projectiles that are spawned by [`Attack::Ranged`].
+
+use bevy::prelude::*;
+use bevy_enoki::prelude::*;
+use bevy_prng::WyRand;
+use bevy_rapier2d::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    animations::prelude::*, characters::prelude::*, log::prelude::*, physics::prelude::*,
+    render::prelude::*,
+};
+
+/// Color of a [`Projectile`] sprite.
+const PROJECTILE_COLOR: Color = Color::srgb(0.45, 0.42, 0.4);
+
+/// Relevant data for a [`Projectile`] deserialized from a ron file.
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct ProjectileData {
+    /// Speed in pixels per second.
+    pub(crate) speed: f32,
+    /// Lifetime in seconds after which the [`Projectile`] is despawned.
+    pub(crate) lifetime_secs: f32,
+}
+
+/// Projectile that triggers [`Damage`] on the first [`Collider`] it hits.
+#[derive(Component)]
+pub(crate) struct Projectile {
+    /// [`Entity`] that has spawned this and that is ignored on hits.
+    pub(crate) source: Entity,
+    /// Whether [`Projectile::source`] is an [`Npc`].
+    ///
+    /// Characters of the same side are ignored on hits.
+    pub(crate) is_npc: bool,
+    pub(crate) damage: f32,
+    /// Distance in pixels that the target is pushed away.
+    pub(crate) knockback: f32,
+    /// [`StatusEffectData`] that is applied to the target on hit.
+    pub(crate) effects: Vec<StatusEffectData>,
+    /// Velocity in pixels per second.
+    pub(crate) velocity: Vec2,
+}
+
+/// Timer that tracks the lifetime of a [`Projectile`].
+#[derive(Component, Debug, Clone, PartialEq, Reflect, Deref, DerefMut)]
+#[reflect(Component)]
+pub(crate) struct ProjectileTimer(pub(crate) Timer);
+
+/// On [`Attack::Ranged`], spawn a [`Projectile`] in [`AimDirection`].
+///
+/// This falls back to [`FacingDirection`] if [`AimDirection`] is zero.
+pub(super) fn on_ranged_attack<T>(
+    mut reader: MessageReader<Attack>,
+    mut rng: Single<&mut WyRand, With<AnimationRng>>,
+    mut origin_query: Query<
+        (
+            &Transform,
+            &AimDirection,
+            &FacingDirection,
+            &AttackStats,
+            &ChildOf,
+            &mut AnimationState,
+            Has<Npc>,
+        ),
+        With<T>,
+    >,
+    mut commands: Commands,
+    collision_data: Res<CollisionDataCache<T>>,
+    particle: Res<ParticleHandle<ProjectileTrailParticle>>,
+    material: Res<Particle2dMaterialHandle<ProjectileTrailParticleMaterial>>,
+) where
+    T: Visible,
+{
+    for attack in reader.read() {
+        let Attack::Ranged(entity) = attack else {
+            continue;
+        };
+
+        // NOTE: Attacks of other characters are handled by their own systems.
+        let Ok((transform, aim, facing, stats, child_of, mut state, is_npc)) =
+            origin_query.get_mut(*entity)
+        else {
+            continue;
+        };
+        let Some(ranged) = stats.ranged() else {
+            warn_once!("{}", WARN_INVALID_ATTACK_DATA);
+            continue;
+        };
+        let Some(projectile) = &ranged.projectile else {
+            warn_once!("{}", WARN_INVALID_ATTACK_DATA);
+            continue;
+        };
+        let direction = aim.0.try_normalize().unwrap_or(facing.0);
+
+        // Spawn outside of the source `Collider`
+        // NOTE: `CollisionDataCache` is unscaled, so the `Archetype` scale has to be applied.
+        let extent = (collision_data.width / 2.).max(collision_data.height / 2.)
+            * transform.scale.xy().max_element();
+        let size = ranged.range();
+        let pos = transform.translation.xy() + direction * (extent + size.x / 2.);
+
+        // NOTE: The projectile is added to the level of the source so that the level handles despawning.
+        commands.entity(child_of.parent()).with_child((
+            Name::new("Projectile"),
+            Projectile {
+                source: *entity,
+                is_npc,
+                damage: stats.damage_factor * ranged.damage,
+                knockback: ranged.knockback,
+                effects: ranged.effects.clone(),
+                velocity: direction * projectile.speed,
+            },
+            ProjectileTimer(Timer::from_seconds(
+                projectile.lifetime_secs,
+                TimerMode::Once,
+            )),
+            // Positioning/Visibility
+            (
+                Sprite::from_color(PROJECTILE_COLOR, size),
+                Transform::from_translation(pos.extend(FOREGROUND_Z))
+                    .with_rotation(Quat::from_rotation_z(direction.to_angle())),
+                YSort(FOREGROUND_Z),
+                Visibility::Inherited,
+            ),
+            // Physics
+            (
+                RigidBody::KinematicPositionBased,
+                Collider::ball(size.max_element() / 2.),
+                Sensor,
+                // NOTE: Characters are kinematic and props are fixed, so these have to be enabled.
+                ActiveCollisionTypes::default()
+                    | ActiveCollisionTypes::KINEMATIC_KINEMATIC
+                    | ActiveCollisionTypes::KINEMATIC_STATIC,
+            ),
+            children![(
+                ProjectileTrailParticle,
+                ParticleSpawner(material.0.clone()),
+                NoAutoAabb,
+                ParticleEffectHandle(
+                    ranged
+                        .particle_handle
+                        .clone()
+                        .unwrap_or_else(|| particle.handle.clone())
+                ),
+                Transform::from_xyz(0., 0., -LAYER_Z_DELTA),
+            )],
+        ));
+        ranged.play_effects(&mut commands, &mut rng, *entity, &mut state);
+    }
+}
+
+/// Move [`Projectile`]s by their velocity.
+pub(super) fn move_projectiles(mut query: Query<(&mut Transform, &Projectile)>, time: Res<Time>) {
+    for (mut transform, projectile) in &mut query {
+        transform.translation += (projectile.velocity * time.delta_secs()).extend(0.);
+    }
+}
+
+/// Trigger [`Damage`] on the first [`Collider`] a [`Projectile`] hits and despawn it.
+///
+/// Other [`Projectile`]s, the source and characters of the same side as the source are ignored.
+pub(super) fn hit_projectiles(
+    projectile_query: Query<(Entity, &Projectile)>,
+    target_query: Query<Has<Npc>, With<Health>>,
+    mut commands: Commands,
+    rapier_context: ReadRapierContext,
+) {
+    let rapier_context = rapier_context.single().expect(ERR_INVALID_RAPIER_CONTEXT);
+
+    for (entity, projectile) in &projectile_query {
+        let hit = rapier_context
+            .intersection_pairs_with(entity)
+            .filter(|(_, _, intersecting)| *intersecting)
+            .map(|(a, b, _)| if a == entity { b } else { a })
+            .find(|e| {
+                *e != projectile.source
+                    && !projectile_query.contains(*e)
+                    && target_query.get(*e).ok() != Some(projectile.is_npc)
+            });
+        let Some(target) = hit else {
+            continue;
+        };
+
+        if target_query.contains(target) {
+            commands.trigger(Damage {
+                targets: vec![target],
+                damage: projectile.damage,
+                direction: projectile.velocity.normalize_or_zero(),
+                knockback: projectile.knockback,
+                effects: projectile.effects.clone(),
+                source: DamageSource::Attack(projectile.source),
+            });
+        }
+        // NOTE: Using try here is necessary since the entity might have been despawned elsewhere.
+        commands.entity(entity).try_despawn();
+    }
+}
+
+/// Despawn [`Projectile`]s after their [`ProjectileTimer`] has finished.
+pub(super) fn despawn_expired_projectiles(
+    query: Query<(Entity, &ProjectileTimer), With<Projectile>>,
+    mut commands: Commands,
+) {
+    for (entity, timer) in &query {
+        if timer.0.is_finished() {
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
@@ -0,0 +1,269 @@
+//! Timed status effects like poison, slow and burn.
+
+use bevy::{color::palettes::tailwind, prelude::*};
+use bevy_enoki::prelude::*;
+use serde::Deserialize;
+
+use crate::{characters::prelude::*, render::prelude::*};
+
+/// Distance in px to the center of a [`StandardMist`] within which the [`Player`] is poisoned.
+const MIST_RADIUS: f32 = 64.;
+
+/// [`StatusEffectData::duration_secs`] of the poison applied by [`StandardMist`].
+const MIST_POISON_DURATION_SECS: f32 = 1.;
+
+/// [`StatusEffectData::magnitude`] of the poison applied by [`StandardMist`].
+const MIST_POISON_DAMAGE_PER_SEC: f32 = 0.25;
+
+/// Interval in seconds that [`StandardMist`] reapplies its poison with.
+///
+/// This is shorter than [`MIST_POISON_DURATION_SECS`], so the poison lasts while in the mist.
+const MIST_POISON_INTERVAL_SECS: f32 = 0.5;
+
+/// Kind of [`StatusEffect`] deserialized from a ron file.
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub(crate) enum StatusEffectKind {
+    /// Damage over time that stacks once per source.
+    Poison,
+    /// Damage over time.
+    Burn,
+    /// Reduces [`WalkSpeed`].
+    Slow,
+    /// Reduces [`AttackStats::damage_factor`].
+    Weaken,
+}
+impl StatusEffectKind {
+    /// Color that [`Sprite`]s of affected [`Character`]s are tinted with.
+    fn color(self) -> Color {
+        match self {
+            Self::Poison => tailwind::LIME_300.into(),
+            Self::Burn => tailwind::ORANGE_300.into(),
+            Self::Slow => tailwind::SKY_300.into(),
+            Self::Weaken => tailwind::STONE_400.into(),
+        }
+    }
+
+    /// Whether effects of this kind from different sources stack.
+    ///
+    /// Otherwise a single effect of this kind is refreshed on every application.
+    fn stacks(self) -> bool {
+        self == Self::Poison
+    }
+}
+
+/// Relevant data for a [`StatusEffect`] deserialized from a ron file.
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct StatusEffectData {
+    pub(crate) kind: StatusEffectKind,
+    pub(crate) duration_secs: f32,
+    /// Damage per second for [`StatusEffectKind::Poison`] and [`StatusEffectKind::Burn`].
+    ///
+    /// Fraction that [`WalkSpeed`] or [`AttackStats::damage_factor`] is reduced by otherwise.
+    pub(crate) magnitude: f32,
+    /// Path of the particle effect. Falls back to the default [`StatusParticle`] if this is [`None`].
+    #[serde(default)]
+    pub(crate) particle: Option<String>,
+    #[serde(skip)]
+    pub(crate) particle_handle: Option<Handle<Particle2dEffect>>,
+}
+impl StatusEffectData {
+    pub(crate) fn new(kind: StatusEffectKind, duration_secs: f32, magnitude: f32) -> Self {
+        Self {
+            kind,
+            duration_secs,
+            magnitude,
+            particle: None,
+            particle_handle: None,
+        }
+    }
+}
+
+/// Status effect that is applied to the [`Entity`] of [`StatusEffectOf`].
+#[derive(Component, Debug)]
+pub(crate) struct StatusEffect {
+    pub(crate) kind: StatusEffectKind,
+    pub(crate) magnitude: f32,
+    /// [`Entity`] that has applied this.
+    pub(crate) source: Option<Entity>,
+}
+
+/// Timer that tracks the remaining duration of a [`StatusEffect`].
+#[derive(Component, Debug, Clone, PartialEq, Reflect, Deref, DerefMut)]
+#[reflect(Component)]
+pub(crate) struct StatusEffectTimer(pub(crate) Timer);
+
+/// [`Entity`] that a [`StatusEffect`] is applied to.
+#[derive(Component, Debug)]
+#[relationship(relationship_target = StatusEffects)]
+pub(crate) struct StatusEffectOf(pub(crate) Entity);
+
+/// All [`StatusEffect`]s applied to this [`Entity`].
+#[derive(Component, Debug, Deref)]
+#[relationship_target(relationship = StatusEffectOf, linked_spawn)]
+pub(crate) struct StatusEffects(Vec<Entity>);
+
+/// Modifiers that result from all [`StatusEffects`] of a [`Character`].
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub(crate) struct StatusModifiers {
+    /// Factor that [`WalkSpeed`] is multiplied with.
+    pub(crate) walk_speed: f32,
+    /// Factor that [`AttackStats::damage_factor`] is multiplied with.
+    pub(crate) damage_factor: f32,
+    /// Color of the most recently applied [`StatusEffect`] if there is one.
+    pub(crate) tint: Option<Color>,
+}
+impl Default for StatusModifiers {
+    fn default() -> Self {
+        Self {
+            walk_speed: 1.,
+            damage_factor: 1.,
+            tint: None,
+        }
+    }
+}
+
+/// [`EntityEvent`] that applies a [`StatusEffect`] to the contained [`Entity`].
+#[derive(EntityEvent)]
+pub(crate) struct ApplyStatusEffect {
+    pub(crate) entity: Entity,
+    /// [`Entity`] that applies the [`StatusEffect`].
+    pub(crate) source: Option<Entity>,
+    pub(crate) effect: StatusEffectData,
+}
+
+/// Spawn a [`StatusEffect`] or refresh an existing one according to [`StatusEffectKind::stacks`].
+///
+/// Refreshing resets [`StatusEffectTimer`] and keeps the higher magnitude.
+pub(super) fn on_apply_status_effect(
+    event: On<ApplyStatusEffect>,
+    target_query: Query<(&Health, Option<&StatusEffects>), With<StatusModifiers>>,
+    mut effect_query: Query<(&mut StatusEffect, &mut StatusEffectTimer)>,
+    mut commands: Commands,
+    particle: Res<ParticleHandle<StatusParticle>>,
+) {
+    let Ok((health, effects)) = target_query.get(event.entity) else {
+        return;
+    };
+    if !health.is_alive() {
+        return;
+    }
+    let data = &event.effect;
+
+    // Refresh existing effect
+    let existing = effects
+        .into_iter()
+        .flat_map(|e| e.iter())
+        .copied()
+        .find(|e| {
+            effect_query.get(*e).is_ok_and(|(effect, _)| {
+                effect.kind == data.kind && (!data.kind.stacks() || effect.source == event.source)
+            })
+        });
+    if let Some(existing) = existing
+        && let Ok((mut effect, mut timer)) = effect_query.get_mut(existing)
+    {
+        effect.magnitude = effect.magnitude.max(data.magnitude);
+        timer.0 = Timer::from_seconds(data.duration_secs, TimerMode::Once);
+        return;
+    }
+
+    commands.spawn((
+        Name::new("Status Effect"),
+        StatusEffect {
+            kind: data.kind,
+            magnitude: data.magnitude,
+            source: event.source,
+        },
+        StatusEffectTimer(Timer::from_seconds(data.duration_secs, TimerMode::Once)),
+        StatusEffectOf(event.entity),
+    ));
+    commands.trigger(SpawnChildParticleOnce::<StatusParticle>::new(
+        event.entity,
+        Vec3::new(0., 0., Y_SORT_OVERRIDE_Z_DELTA),
+        data.particle_handle
+            .clone()
+            .unwrap_or_else(|| particle.handle.clone()),
+    ));
+}
+
+/// Update [`StatusModifiers`] from [`StatusEffects`] and trigger [`Damage`] over time.
+///
+/// [`Damage`] is triggered per [`StatusEffect`], so kills are rewarded to its [`StatusEffect::source`].
+pub(super) fn update_status_effects(
+    target_query: Query<(Entity, &mut StatusModifiers, Option<&StatusEffects>)>,
+    effect_query: Query<&StatusEffect>,
+    mut commands: Commands,
+    time: Res<Time>,
+) {
+    for (entity, mut modifiers, effects) in target_query {
+        let mut next = StatusModifiers::default();
+        for effect in effect_query.iter_many(effects.into_iter().flat_map(|e| e.iter())) {
+            let factor = (1. - effect.magnitude).clamp(0., 1.);
+            match effect.kind {
+                StatusEffectKind::Poison | StatusEffectKind::Burn => {
+                    commands.trigger(Damage {
+                        targets: vec![entity],
+                        damage: effect.magnitude * time.delta_secs(),
+                        direction: Vec2::ZERO,
+                        knockback: 0.,
+                        effects: Vec::new(),
+                        source: DamageSource::Status(effect.source),
+                    });
+                }
+                StatusEffectKind::Slow => next.walk_speed = next.walk_speed.min(factor),
+                StatusEffectKind::Weaken => next.damage_factor = next.damage_factor.min(factor),
+            }
+            next.tint = Some(effect.kind.color());
+        }
+        modifiers.set_if_neq(next);
+    }
+}
+
+/// Despawn [`StatusEffect`]s after their [`StatusEffectTimer`] has finished.
+pub(super) fn despawn_expired_status_effects(
+    query: Query<(Entity, &StatusEffectTimer), With<StatusEffect>>,
+    mut commands: Commands,
+) {
+    for (entity, timer) in &query {
+        if timer.0.is_finished() {
+            // NOTE: Using try here is necessary since the entity might have been despawned elsewhere.
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
+
+/// Poison the [`Player`] within [`MIST_RADIUS`] of a [`StandardMist`].
+///
+/// This is rate limited to [`MIST_POISON_INTERVAL_SECS`].
+/// Each [`StandardMist`] is a separate source, so overlapping mist stacks.
+pub(super) fn apply_mist_effects(
+    player: Single<(Entity, &GlobalTransform), With<Player>>,
+    mist_query: Query<(Entity, &GlobalTransform), With<StandardMist>>,
+    mut commands: Commands,
+    mut elapsed: Local<f32>,
+    time: Res<Time>,
+) {
+    *elapsed += time.delta_secs();
+    if *elapsed < MIST_POISON_INTERVAL_SECS {
+        return;
+    }
+    *elapsed = 0.;
+
+    let (entity, transform) = *player;
+    let pos = transform.translation().xy();
+
+    for (mist, mist_transform) in &mist_query {
+        if mist_transform.translation().xy().distance(pos) > MIST_RADIUS {
+            continue;
+        }
+        commands.trigger(ApplyStatusEffect {
+            entity,
+            source: Some(mist),
+            effect: StatusEffectData::new(
+                StatusEffectKind::Poison,
+                MIST_POISON_DURATION_SECS,
+                MIST_POISON_DAMAGE_PER_SEC,
+            ),
+        });
+    }
+}
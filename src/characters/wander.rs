@@ -0,0 +1,105 @@
+//! Idle wandering of [`Npc`]s that do not navigate to a [`NavTarget`].
+
+use std::{f32::consts::TAU, ops::Range};
+
+use bevy::prelude::*;
+use bevy_prng::WyRand;
+use bevy_rapier2d::prelude::*;
+use rand::RngExt as _;
+
+use crate::{animations::prelude::*, characters::prelude::*, utils::prelude::*};
+
+/// Maximum distance in px from [`Wander::origin`] that targets are chosen within.
+const WANDER_RADIUS: f32 = 48.;
+
+/// Distance in px to [`Wander::target`] that counts as arrived.
+const WANDER_ARRIVAL_DISTANCE: f32 = 1.;
+
+/// [`Range`] in seconds to idle for before choosing the next [`Wander::target`].
+const WANDER_IDLE_RANGE_SECS: Range<f32> = 2.0..6.0;
+
+/// Maximum duration in seconds to walk towards [`Wander::target`].
+///
+/// This prevents walking against obstacles indefinitely.
+const WANDER_MAX_WALK_SECS: f32 = 4.;
+
+/// Wandering around [`Wander::origin`] by alternately idling and walking to random targets.
+#[derive(Component, Default, Reflect)]
+pub(crate) struct Wander {
+    pub(crate) origin: Vec2,
+    pub(crate) target: Option<Vec2>,
+}
+impl Wander {
+    pub(crate) fn new(origin: Vec2) -> Self {
+        Self {
+            origin,
+            target: None,
+        }
+    }
+}
+
+/// Timer that tracks idling and walking of [`Wander`].
+#[derive(Component, Debug, Clone, PartialEq, Reflect, Deref, DerefMut)]
+#[reflect(Component)]
+pub(crate) struct WanderTimer(pub(crate) Timer);
+
+/// Rng for wandering
+#[derive(Component, Default, TypePath)]
+pub(super) struct WanderRng;
+impl ForkedRng for WanderRng {}
+
+/// Apply [`Wander`].
+///
+/// Targets are chosen after idling and walking stops at the target or after [`WANDER_MAX_WALK_SECS`].
+pub(super) fn wander(
+    query: Query<
+        (
+            &Transform,
+            &mut Wander,
+            &mut WanderTimer,
+            &mut AnimationState,
+            &mut KinematicCharacterController,
+            &WalkSpeed,
+            &StatusModifiers,
+        ),
+        Without<HitStunTimer>,
+    >,
+    mut rng: Single<&mut WyRand, With<WanderRng>>,
+    time: Res<Time>,
+) {
+    for (
+        transform,
+        mut wander,
+        mut timer,
+        mut animation_state,
+        mut controller,
+        walk_speed,
+        modifiers,
+    ) in query
+    {
+        let pos = transform.translation.xy();
+        let Some(target) = wander.target else {
+            if timer.is_finished() {
+                let offset = Vec2::from_angle(rng.random_range(0.0..TAU))
+                    * rng.random_range(0.0..WANDER_RADIUS);
+                wander.target = Some(wander.origin + offset);
+                timer.0 = Timer::from_seconds(WANDER_MAX_WALK_SECS, TimerMode::Once);
+            }
+            continue;
+        };
+
+        if pos.distance(target) <= WANDER_ARRIVAL_DISTANCE || timer.is_finished() {
+            wander.target = None;
+            timer.0 =
+                Timer::from_seconds(rng.random_range(WANDER_IDLE_RANGE_SECS), TimerMode::Once);
+            animation_state.set_new_action(AnimationAction::Idle);
+            continue;
+        }
+
+        let distance = walk_speed.0 * modifiers.walk_speed * time.delta_secs();
+        controller.translation = Some((target - pos).clamp_length_max(distance));
+        if animation_state.0.0 == AnimationAction::Idle {
+            animation_state.set_new_action(AnimationAction::Walk);
+        }
+    }
+}
@@ -15,8 +15,8 @@ use bevy_rapier2d::render::{DebugRenderContext, RapierDebugRenderPlugin};
 use vleue_navigator::prelude::*;
 
 use crate::{
-    characters::prelude::*, core::prelude::*, procgen::prelude::*, screens::prelude::*,
-    ui::prelude::*,
+    characters::prelude::*, core::prelude::*, levels::prelude::*, procgen::prelude::*,
+    screens::prelude::*, ui::prelude::*,
 };
 
 pub(super) struct DebugPlugin;
@@ -50,6 +50,7 @@ impl Plugin for DebugPlugin {
         app.add_systems(
             Update,
             (
+                log_transitions::<ActiveLevel>,
                 log_transitions::<Debug>,
                 log_transitions::<DespawnProcGen>,
                 log_transitions::<JoystickState<{ JoystickID::MOVEMENT }>>,
@@ -10,7 +10,10 @@ mod tiles;
 mod transitions;
 
 pub(crate) mod prelude {
-    pub(crate) use super::layers::{DisplayLayers, LayerData, LayerDataCache, LayerHandle};
+    pub(crate) use super::layers::{
+        DisplayLayers, DisplayVariant, DisplayVariants, LayerCategory, LayerData, LayerDataCache,
+        LayerHandle, LayerSelection,
+    };
     pub(crate) use super::tiles::{TileData, TileDataCache, TileHandle};
     pub(crate) use super::transitions::{FadeInOut, apply_fade_in_out, tick_fade_in_out};
     pub(crate) use super::{CelSize, ImageMeta, image_from_data};
@@ -22,8 +25,8 @@ use bevy::{asset::RenderAssetUsages, prelude::*, render::render_resource::*};
 use bevy_ecs_tilemap::prelude::*;
 
 use crate::{
-    animations::prelude::*, characters::prelude::*, images::prelude::*, log::prelude::*,
-    render::prelude::*, screens::prelude::*,
+    animations::prelude::*, characters::prelude::*, core::prelude::*, images::prelude::*,
+    log::prelude::*, render::prelude::*, screens::prelude::*,
 };
 
 pub(super) struct ImagesPlugin;
@@ -37,16 +40,29 @@ impl Plugin for ImagesPlugin {
                 (
                     insert_images_and_related::<Player>,
                     insert_images_and_related::<Slime>,
+                    insert_images_and_related::<Villager>,
                 ),
                 (
                     outline::add_outline::<Player>,
                     outline::add_outline::<Slime>,
+                    outline::add_outline::<Villager>,
+                    layers::insert_display_variants::<Villager>,
+                ),
+                (
+                    insert_cel_size::<Player>,
+                    insert_cel_size::<Slime>,
+                    insert_cel_size::<Villager>,
                 ),
-                (insert_cel_size::<Player>, insert_cel_size::<Slime>),
             )
                 .in_set(EnterGameplaySystems::Images)
                 .chain(),
         );
+        app.add_systems(
+            Update,
+            layers::recompose_display_layers::<Player>
+                .run_if(in_state(Screen::Gameplay))
+                .in_set(AppSystems::Update),
+        );
     }
 }
 
@@ -63,7 +79,10 @@ impl<T> CelSize<T>
 where
     T: Visible,
 {
-    fn from_animation_data(animation_data: &AnimationDataCache<T>, meta: &ImageMeta<T>) -> Self {
+    pub(crate) fn from_animation_data(
+        animation_data: &AnimationDataCache<T>,
+        meta: &ImageMeta<T>,
+    ) -> Self {
         // NOTE: This asserts that `atlas_columns` and `atlas_rows` are correct for `meta`.
         assert_eq!(meta.size.width % animation_data.atlas_columns as u32, 0);
         assert_eq!(meta.size.height % animation_data.atlas_rows as u32, 0);
@@ -91,10 +110,13 @@ impl<T> ImageMeta<T>
 where
     T: Visible,
 {
-    fn from_layer_data_cache(data: &LayerDataCache<T>, images: &mut ResMut<Assets<Image>>) -> Self {
+    pub(crate) fn from_layer_data_cache(
+        data: &LayerDataCache<T>,
+        images: &mut ResMut<Assets<Image>>,
+    ) -> Self {
         let (size, dimension, format) = data
-            .base
-            .first()
+            .all_base_layers()
+            .next()
             .map(|image| {
                 let descriptor = &images
                     .get(image)
@@ -108,7 +130,7 @@ where
         assert_eq!(format, TextureFormat::bevy_default());
 
         // NOTE: This asserts that each image has the same metadata.
-        assert!(data.base.iter().all(|image| {
+        assert!(data.all_base_layers().all(|image| {
             let descriptor = &images
                 .get(image)
                 .expect(ERR_INVALID_IMAGE)
@@ -154,11 +176,12 @@ where
     images.add(image)
 }
 
-/// Insert [`DisplayLayers`] and [`ImageMeta`].
+/// Insert [`DisplayLayers`] from [`LayerSelection`] and [`ImageMeta`].
 fn insert_images_and_related<T>(
     mut commands: Commands,
     mut images: ResMut<Assets<Image>>,
     data: Res<LayerDataCache<T>>,
+    selection: Option<Res<LayerSelection<T>>>,
 ) where
     T: Visible,
 {
@@ -166,6 +189,7 @@ fn insert_images_and_related<T>(
 
     commands.insert_resource(DisplayLayers::from_layer_data_cache(
         &data,
+        selection.as_deref(),
         &meta,
         &mut images,
     ));
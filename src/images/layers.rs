@@ -6,20 +6,31 @@
 use std::marker::PhantomData;
 
 use bevy::prelude::*;
+use bevy_prng::WyRand;
+use rand::{RngExt as _, SeedableRng as _};
 use serde::Deserialize;
 
 use crate::{
+    animations::prelude::*,
+    characters::prelude::*,
     images::{image_from_data, prelude::*},
     log::prelude::*,
+    procgen::prelude::*,
     render::prelude::*,
 };
 
+/// Number of [`DisplayVariants`] that are composed for each type.
+const DISPLAY_VARIANT_COUNT: usize = 16;
+
 /// Layer data deserialized from a ron file.
 #[derive(Deserialize, Asset, TypePath, Default)]
 pub(crate) struct LayerData<T>
 where
     T: Visible,
 {
+    /// Selectable layers that are drawn above `base` in order.
+    #[serde(default)]
+    pub(crate) categories: Vec<LayerCategory>,
     #[serde(default)]
     pub(crate) base: Vec<String>,
     #[serde(default)]
@@ -28,6 +39,27 @@ where
     _phantom: PhantomData<T>,
 }
 
+/// Category of interchangeable layers of which a single one is drawn.
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct LayerCategory {
+    pub(crate) name: String,
+    pub(crate) layers: Vec<String>,
+    /// Index into `layers` that is selected by default. No layer is drawn if this is [`None`].
+    #[serde(default)]
+    pub(crate) default: Option<usize>,
+    /// Whether it is possible to select no layer.
+    #[serde(default)]
+    pub(crate) optional: bool,
+    #[serde(skip)]
+    pub(crate) layer_handles: Vec<Handle<Image>>,
+}
+impl LayerCategory {
+    /// Whether there is more than one option to select from.
+    pub(crate) fn is_selectable(&self) -> bool {
+        self.layers.len() + usize::from(self.optional) > 1
+    }
+}
+
 /// Handle for [`LayerData`].
 #[derive(Resource)]
 pub(crate) struct LayerHandle<T>(pub(crate) Handle<LayerData<T>>)
@@ -42,10 +74,112 @@ pub(crate) struct LayerDataCache<T>
 where
     T: Visible,
 {
+    pub(crate) categories: Vec<LayerCategory>,
     pub(crate) base: Vec<Handle<Image>>,
     pub(crate) floating: Option<Vec<Handle<Image>>>,
     pub(crate) _phantom: PhantomData<T>,
 }
+impl<T> LayerDataCache<T>
+where
+    T: Visible,
+{
+    /// Selected layers of `categories` followed by `base`.
+    ///
+    /// Falls back to [`LayerCategory::default`] for categories without a valid selection.
+    pub(crate) fn base_layers(&self, selection: Option<&LayerSelection<T>>) -> Vec<Handle<Image>> {
+        self.categories
+            .iter()
+            .enumerate()
+            .filter_map(|(i, category)| {
+                selection
+                    .and_then(|s| s.get(i).copied())
+                    .filter(|s| s.is_none_or(|s| s < category.layer_handles.len()))
+                    .unwrap_or(category.default)
+                    .and_then(|s| category.layer_handles.get(s).cloned())
+            })
+            .chain(self.base.iter().cloned())
+            .collect()
+    }
+
+    /// All base layers including every layer of `categories`.
+    pub(crate) fn all_base_layers(&self) -> impl Iterator<Item = &Handle<Image>> {
+        self.categories
+            .iter()
+            .flat_map(|c| c.layer_handles.iter())
+            .chain(self.base.iter())
+    }
+}
+
+/// Selected layer of each [`LayerCategory`] in [`LayerDataCache`].
+#[derive(Resource, Debug, Deref)]
+pub(crate) struct LayerSelection<T>
+where
+    T: Visible,
+{
+    #[deref]
+    pub(crate) selection: Vec<Option<usize>>,
+    pub(crate) _phantom: PhantomData<T>,
+}
+impl<T> LayerSelection<T>
+where
+    T: Visible,
+{
+    /// [`LayerSelection`] with [`LayerCategory::default`] of each category.
+    pub(crate) fn new(data: &LayerDataCache<T>) -> Self {
+        Self::from_selection(data.categories.iter().map(|c| c.default).collect())
+    }
+
+    pub(crate) fn from_selection(selection: Vec<Option<usize>>) -> Self {
+        Self {
+            selection,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// [`LayerSelection`] with a random layer of each category.
+    ///
+    /// If the category is [`LayerCategory::optional`], selecting no layer is as likely as any layer.
+    pub(crate) fn random(data: &LayerDataCache<T>, rng: &mut WyRand) -> Self {
+        Self::from_selection(
+            data.categories
+                .iter()
+                .map(|category| {
+                    let offset = usize::from(category.optional);
+                    let len = category.layer_handles.len() + offset;
+                    (len > 0)
+                        .then(|| rng.random_range(0..len))
+                        .and_then(|i| i.checked_sub(offset))
+                })
+                .collect(),
+        )
+    }
+
+    /// Select the next or previous layer of the category at `index`.
+    ///
+    /// If the category is [`LayerCategory::optional`], selecting no layer is part of the cycle.
+    pub(crate) fn cycle(&mut self, data: &LayerDataCache<T>, index: usize, forward: bool) {
+        let Some(category) = data.categories.get(index) else {
+            return;
+        };
+        if self.selection.len() < data.categories.len() {
+            self.selection.resize(data.categories.len(), None);
+        }
+
+        // NOTE: Options are the layers preceded by no layer if the category is optional.
+        let offset = usize::from(category.optional);
+        let len = category.layer_handles.len() + offset;
+        if len == 0 {
+            return;
+        }
+        let current = self.selection[index].map_or(0, |i| i + offset);
+        let next = if forward {
+            (current + 1) % len
+        } else {
+            (current + len - 1) % len
+        };
+        self.selection[index] = (next >= offset).then(|| next - offset);
+    }
+}
 
 /// [`Image`] for displaying `T`
 #[derive(Resource, Default)]
@@ -63,17 +197,30 @@ where
 {
     pub(crate) fn from_layer_data_cache(
         data: &LayerDataCache<T>,
+        selection: Option<&LayerSelection<T>>,
         meta: &ImageMeta<T>,
         images: &mut ResMut<Assets<Image>>,
     ) -> DisplayLayers<T>
     where
         T: Visible,
     {
-        let base = layered_image(data.base.clone(), meta, images);
-        let floating = data
-            .floating
-            .as_ref()
-            .map(|f| layered_image(f.clone(), meta, images));
+        Self::from_layers(
+            data.base_layers(selection),
+            data.floating.clone(),
+            meta,
+            images,
+        )
+    }
+
+    /// [`DisplayLayers`] from `base` and `floating` layers with the first layer being the top layer.
+    fn from_layers(
+        base: Vec<Handle<Image>>,
+        floating: Option<Vec<Handle<Image>>>,
+        meta: &ImageMeta<T>,
+        images: &mut ResMut<Assets<Image>>,
+    ) -> Self {
+        let base = layered_image(base, meta, images);
+        let floating = floating.map(|f| layered_image(f, meta, images));
 
         Self {
             base,
@@ -83,6 +230,18 @@ where
     }
 }
 
+/// [`DisplayLayers`] of random [`LayerSelection`]s for individuals of `T`.
+///
+/// Individuals choose from these with [`DisplayVariant`].
+#[derive(Resource, Deref)]
+pub(crate) struct DisplayVariants<T>(pub(crate) Vec<DisplayLayers<T>>)
+where
+    T: Visible;
+
+/// Index into [`DisplayVariants`] that is used instead of [`DisplayLayers`].
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+pub(crate) struct DisplayVariant(pub(crate) usize);
+
 /// A single [`Handle<Image>`] from layers.
 fn layered_image<T>(
     layers: Vec<Handle<Image>>,
@@ -115,3 +274,89 @@ where
 
     image_from_data(data, meta, images)
 }
+
+/// Recompose [`DisplayLayers`] from [`LayerDataCache`] and [`Equipment`] and update [`Sprite`]s.
+///
+/// Equipped [`Item`]s are drawn above the layers of [`LayerDataCache`].
+pub(super) fn recompose_display_layers<T>(
+    container: Single<(&Equipment, &Children), (With<T>, Changed<Equipment>)>,
+    mut base_query: Query<(&mut Sprite, Option<&Children>), With<AnimationBase>>,
+    mut floating_query: Query<&mut Sprite, Without<AnimationBase>>,
+    mut images: ResMut<Assets<Image>>,
+    mut layers: ResMut<DisplayLayers<T>>,
+    mut sprite_animations: ResMut<SpriteAnimations<T>>,
+    data: Res<LayerDataCache<T>>,
+    item_data: Res<ItemDataCache<T>>,
+    meta: Res<ImageMeta<T>>,
+    selection: Option<Res<LayerSelection<T>>>,
+) where
+    T: Visible,
+{
+    let (equipment, children) = container.into_inner();
+    let (floating_items, base_items): (Vec<_>, Vec<_>) = equipment
+        .items(&item_data.items)
+        .partition(|i| i.slot.floating());
+    let base = base_items
+        .iter()
+        .map(|i| i.image_handle.clone())
+        .chain(data.base_layers(selection.as_deref()))
+        .collect();
+    // NOTE: Floating items are ignored if there is no floating layer.
+    let floating = data.floating.as_ref().map(|layers| {
+        floating_items
+            .iter()
+            .map(|i| i.image_handle.clone())
+            .chain(layers.iter().cloned())
+            .collect()
+    });
+    *layers = DisplayLayers::from_layers(base, floating, &meta, &mut images);
+    layers.add_outline(&mut images, &meta);
+
+    // Update sprites for characters spawned later on
+    sprite_animations.base.sprite.image = layers.base.clone();
+    if let Some(animation) = &mut sprite_animations.floating
+        && let Some(image) = &layers.floating
+    {
+        animation.sprite.image = image.clone();
+    }
+
+    // Update sprites of the existing character
+    let child = children
+        .iter()
+        .find(|e| base_query.contains(*e))
+        .expect(ERR_INVALID_CHILDREN);
+    let (mut base_sprite, children) = base_query.get_mut(child).expect(ERR_INVALID_CHILDREN);
+    base_sprite.image = layers.base.clone();
+    if let Some(children) = children
+        && let Some(child) = children.iter().find(|e| floating_query.contains(*e))
+        && let Ok(mut floating_sprite) = floating_query.get_mut(child)
+        && let Some(image) = &layers.floating
+    {
+        floating_sprite.image = image.clone();
+    }
+}
+
+/// Insert [`DisplayVariants`] with outlines from [`LayerDataCache`].
+///
+/// These are seeded by [`WorldSeed`], so the same world has the same variants.
+pub(super) fn insert_display_variants<T>(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    data: Res<LayerDataCache<T>>,
+    meta: Res<ImageMeta<T>>,
+    seed: Res<WorldSeed>,
+) where
+    T: ProcGenerated + Visible,
+{
+    let mut rng = WyRand::seed_from_u64(seed.seed::<T>());
+    let variants = (0..DISPLAY_VARIANT_COUNT)
+        .map(|_| {
+            let selection = LayerSelection::random(&data, &mut rng);
+            let mut layers =
+                DisplayLayers::from_layer_data_cache(&data, Some(&selection), &meta, &mut images);
+            layers.add_outline(&mut images, &meta);
+            layers
+        })
+        .collect();
+    commands.insert_resource(DisplayVariants::<T>(variants));
+}
@@ -10,28 +10,42 @@ pub(super) fn add_outline<T>(
 ) where
     T: Visible,
 {
-    let base = images.get(layers.base.id()).expect(ERR_INVALID_IMAGE);
-    let floating = layers
-        .floating
-        .as_ref()
-        .map(|i| images.get(i.id()).expect(ERR_INVALID_IMAGE));
+    layers.add_outline(&mut images, &image_meta);
+}
 
-    let base_data = outlined_image_data(
-        base.data.clone().expect(ERR_INVALID_IMAGE),
-        image_meta.size.width,
-        image_meta.size.height,
-    );
-    let floating_data = floating.map(|i| {
-        outlined_image_data(
-            i.data.clone().expect(ERR_INVALID_IMAGE),
+impl<T> DisplayLayers<T>
+where
+    T: Visible,
+{
+    /// Replace `base` and `floating` with outlined images.
+    pub(crate) fn add_outline(
+        &mut self,
+        images: &mut ResMut<Assets<Image>>,
+        image_meta: &ImageMeta<T>,
+    ) {
+        let base = images.get(self.base.id()).expect(ERR_INVALID_IMAGE);
+        let floating = self
+            .floating
+            .as_ref()
+            .map(|i| images.get(i.id()).expect(ERR_INVALID_IMAGE));
+
+        let base_data = outlined_image_data(
+            base.data.clone().expect(ERR_INVALID_IMAGE),
             image_meta.size.width,
             image_meta.size.height,
-        )
-    });
+        );
+        let floating_data = floating.map(|i| {
+            outlined_image_data(
+                i.data.clone().expect(ERR_INVALID_IMAGE),
+                image_meta.size.width,
+                image_meta.size.height,
+            )
+        });
 
-    layers.base = image_from_data(base_data, &image_meta, &mut images);
-    if let Some(floating_data) = floating_data {
-        layers.floating = Some(image_from_data(floating_data, &image_meta, &mut images));
+        self.base = image_from_data(base_data, image_meta, images);
+        if let Some(floating_data) = floating_data {
+            self.floating = Some(image_from_data(floating_data, image_meta, images));
+        }
     }
 }
 
@@ -1,11 +1,19 @@
 use std::marker::PhantomData;
 
-use bevy::{platform::collections::HashSet, prelude::*};
+use bevy::prelude::*;
 use serde::Deserialize;
 
 use crate::procgen::prelude::*;
 
 /// Tile data deserialized from a ron file.
+///
+/// Each set contains tile coordinates `(x, y)` in the tile set.
+///
+/// Sets that represent transitions are ordered by orientation:
+///
+/// - Corners are ordered by the corner that differs from the rest of the tile: bottom left, bottom right, top right, top left.
+/// - Sides are ordered by the side containing grass: bottom, right, top, left.
+/// - Diagonal stripes are ordered by the grass corners: bottom left and top right, bottom right and top left.
 #[derive(Deserialize, Asset, TypePath, Default)]
 pub(crate) struct TileData<T>
 where
@@ -13,17 +21,17 @@ where
 {
     pub(crate) tile_size: f32,
     #[serde(default)]
-    pub(crate) full_dirt: Option<HashSet<(usize, usize)>>,
+    pub(crate) full_dirt: Option<Vec<(usize, usize)>>,
     #[serde(default)]
-    pub(crate) full_grass: Option<HashSet<(usize, usize)>>,
+    pub(crate) full_grass: Option<Vec<(usize, usize)>>,
     #[serde(default)]
-    pub(crate) corner_outer_grass_to_dirt: Option<HashSet<(usize, usize)>>,
+    pub(crate) corner_outer_grass_to_dirt: Option<Vec<(usize, usize)>>,
     #[serde(default)]
-    pub(crate) corner_outer_dirt_to_grass: Option<HashSet<(usize, usize)>>,
+    pub(crate) corner_outer_dirt_to_grass: Option<Vec<(usize, usize)>>,
     #[serde(default)]
-    pub(crate) side_dirt_and_grass: Option<HashSet<(usize, usize)>>,
+    pub(crate) side_dirt_and_grass: Option<Vec<(usize, usize)>>,
     #[serde(default)]
-    pub(crate) diag_stripe_grass_in_dirt: Option<HashSet<(usize, usize)>>,
+    pub(crate) diag_stripe_grass_in_dirt: Option<Vec<(usize, usize)>>,
     #[serde(skip)]
     pub(crate) _phantom: PhantomData<T>,
 }
@@ -37,17 +45,34 @@ where
 /// Cache for [`TileData`]
 ///
 /// This is to allow easier access.
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Clone)]
 pub(crate) struct TileDataCache<T>
 where
     T: ProcGenerated,
 {
     pub(crate) tile_size: f32,
-    pub(crate) _full_dirt: Option<HashSet<(usize, usize)>>,
-    pub(crate) _full_grass: Option<HashSet<(usize, usize)>>,
-    pub(crate) _corner_outer_grass_to_dirt: Option<HashSet<(usize, usize)>>,
-    pub(crate) _corner_outer_dirt_to_grass: Option<HashSet<(usize, usize)>>,
-    pub(crate) _side_dirt_and_grass: Option<HashSet<(usize, usize)>>,
-    pub(crate) _diag_stripe_grass_in_dirt: Option<HashSet<(usize, usize)>>,
+    pub(crate) full_dirt: Option<Vec<(usize, usize)>>,
+    pub(crate) full_grass: Option<Vec<(usize, usize)>>,
+    pub(crate) corner_outer_grass_to_dirt: Option<Vec<(usize, usize)>>,
+    pub(crate) corner_outer_dirt_to_grass: Option<Vec<(usize, usize)>>,
+    pub(crate) side_dirt_and_grass: Option<Vec<(usize, usize)>>,
+    pub(crate) diag_stripe_grass_in_dirt: Option<Vec<(usize, usize)>>,
     pub(crate) _phantom: PhantomData<T>,
 }
+impl<T> TileDataCache<T>
+where
+    T: ProcGenerated,
+{
+    /// Set of tile coordinates by its field name.
+    pub(crate) fn set(&self, name: &str) -> Option<&Vec<(usize, usize)>> {
+        match name {
+            "full_dirt" => self.full_dirt.as_ref(),
+            "full_grass" => self.full_grass.as_ref(),
+            "corner_outer_grass_to_dirt" => self.corner_outer_grass_to_dirt.as_ref(),
+            "corner_outer_dirt_to_grass" => self.corner_outer_dirt_to_grass.as_ref(),
+            "side_dirt_and_grass" => self.side_dirt_and_grass.as_ref(),
+            "diag_stripe_grass_in_dirt" => self.diag_stripe_grass_in_dirt.as_ref(),
+            _ => None,
+        }
+    }
+}
@@ -5,7 +5,9 @@ mod ui;
 
 pub(crate) mod prelude {
     pub(crate) use super::InputSystems;
-    pub(crate) use super::actions::{Aim, Jump, Melee, Walk, player_input};
+    pub(crate) use super::actions::{
+        Aim, CycleAttack, Dash, Jump, Melee, Ranged, StartDash, Walk, player_input,
+    };
     pub(crate) use super::pointer::{MouseDrag, PointerStartTimeSecs, Swipe};
     pub(crate) use super::ui::scroll::{AutoScroll, InputScroll};
     pub(crate) use super::ui::{PointerBlockedByUi, UiNav, UiNavAction, UiNavActionSet};
@@ -26,10 +28,15 @@ impl Plugin for InputPlugin {
         app.add_input_context::<Player>();
 
         app.add_observer(actions::apply_walk);
+        app.add_observer(actions::cycle_attack);
         app.add_observer(actions::init_melee_attack);
+        app.add_observer(actions::init_ranged_attack);
+        app.add_observer(actions::on_start_dash);
         app.add_observer(actions::reset_walk);
+        app.add_observer(actions::set_dash);
         app.add_observer(actions::set_jump);
-        app.add_observer(mock::reset_aim_mock);
+        app.add_observer(mock::reset_aim_mock::<Melee>);
+        app.add_observer(mock::reset_aim_mock::<Ranged>);
 
         app.configure_sets(
             PreUpdate,
@@ -44,7 +51,9 @@ impl Plugin for InputPlugin {
                 mock::mock_walk_from_virtual_joystick,
                 (
                     mock::mock_jump_from_touch,
+                    (mock::mock_dash_from_drag, mock::mock_dash_from_touch),
                     (mock::mock_melee_from_click, mock::mock_melee_from_touch).chain(),
+                    mock::mock_ranged_from_click,
                     (mock::mock_aim_from_click, mock::mock_aim_from_touch).chain(),
                 )
                     .run_if(in_state(Screen::Gameplay))
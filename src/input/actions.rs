@@ -2,7 +2,10 @@ use bevy::prelude::*;
 use bevy_enhanced_input::prelude::*;
 use bevy_rapier2d::prelude::*;
 
-use crate::{animations::prelude::*, characters::prelude::*, core::prelude::*, log::prelude::*};
+use crate::{
+    animations::prelude::*, characters::prelude::*, core::prelude::*, log::prelude::*,
+    render::prelude::*,
+};
 
 /// Walk [`InputAction`]
 #[derive(InputAction)]
@@ -14,11 +17,35 @@ pub(crate) struct Walk;
 #[action_output(bool)]
 pub(crate) struct Jump;
 
+/// Dash [`InputAction`]
+#[derive(InputAction)]
+#[action_output(bool)]
+pub(crate) struct Dash;
+
+/// [`EntityEvent`] that makes the [`Player`] dash in `direction`.
+///
+/// This is triggered by [`Dash`] and by swipes that have their own direction.
+#[derive(EntityEvent)]
+pub(crate) struct StartDash {
+    pub(crate) entity: Entity,
+    pub(crate) direction: Vec2,
+}
+
 /// Melee attack [`InputAction`]
 #[derive(InputAction)]
 #[action_output(bool)]
 pub(crate) struct Melee;
 
+/// Ranged attack [`InputAction`]
+#[derive(InputAction)]
+#[action_output(bool)]
+pub(crate) struct Ranged;
+
+/// Cycle attacks [`InputAction`]
+#[derive(InputAction)]
+#[action_output(bool)]
+pub(crate) struct CycleAttack;
+
 /// Aim direction [`InputAction`]
 #[derive(InputAction)]
 #[action_output(Vec2)]
@@ -47,11 +74,23 @@ pub(crate) fn player_input() -> impl Bundle {
                 Action::<Jump>::new(),
                 bindings![KeyCode::Space, GamepadButton::South],
             ),
+            (
+                Action::<Dash>::new(),
+                bindings![KeyCode::ShiftLeft, GamepadButton::East],
+            ),
             // Attack
             (
                 Action::<Melee>::new(),
                 bindings![GamepadButton::RightTrigger],
             ),
+            (
+                Action::<Ranged>::new(),
+                bindings![GamepadButton::LeftTrigger],
+            ),
+            (
+                Action::<CycleAttack>::new(),
+                bindings![KeyCode::KeyQ, GamepadButton::North],
+            ),
             (
                 Action::<Aim>::new(),
                 ActionSettings {
@@ -73,8 +112,9 @@ pub(super) fn apply_walk(
             &mut AnimationState,
             &mut KinematicCharacterController,
             &WalkSpeed,
+            &StatusModifiers,
         ),
-        With<Player>,
+        (With<Player>, Without<HitStunTimer>, Without<DashMotion>),
     >,
     pause: Res<State<Pause>>,
     time: Res<Time>,
@@ -83,8 +123,8 @@ pub(super) fn apply_walk(
         return;
     }
 
-    let (mut animation_state, mut controller, walk_speed) = player.into_inner();
-    let direction = event.value * walk_speed.0 * time.delta_secs();
+    let (mut animation_state, mut controller, walk_speed, modifiers) = player.into_inner();
+    let direction = event.value * walk_speed.0 * modifiers.walk_speed * time.delta_secs();
     controller.translation = Some(direction);
 
     if animation_state.0.0 == AnimationAction::Idle {
@@ -95,7 +135,10 @@ pub(super) fn apply_walk(
 /// On a completed [`Walk`], set translation to zero.
 pub(super) fn reset_walk(
     _: On<Complete<Walk>>,
-    player: Single<(&mut AnimationState, &mut KinematicCharacterController), With<Player>>,
+    player: Single<
+        (&mut AnimationState, &mut KinematicCharacterController),
+        (With<Player>, Without<DashMotion>),
+    >,
 ) {
     let (mut animation_state, mut controller) = player.into_inner();
 
@@ -126,11 +169,94 @@ pub(super) fn set_jump(
     }
 }
 
+/// On a fired [`Dash`], trigger [`StartDash`] in the [`FacingDirection`] of the [`Player`].
+pub(super) fn set_dash(
+    _: On<Fire<Dash>>,
+    player: Single<(Entity, &FacingDirection), With<Player>>,
+    mut commands: Commands,
+) {
+    let (entity, facing) = *player;
+    commands.trigger(StartDash {
+        entity,
+        direction: facing.0,
+    });
+}
+
+/// On [`StartDash`], add [`DashMotion`], [`DashTimer`] and [`DashCooldownTimer`].
+///
+/// The [`Player`] turns towards [`StartDash::direction`] and is invulnerable while dashing.
+/// Dashing is not possible while jumping.
+pub(super) fn on_start_dash(
+    event: On<StartDash>,
+    mut player_query: Query<
+        (
+            &Transform,
+            &mut FacingDirection,
+            &mut AnimationState,
+            Option<&InvulnerabilityTimer>,
+        ),
+        (
+            With<Player>,
+            Without<HitStunTimer>,
+            Without<DashCooldownTimer>,
+        ),
+    >,
+    mut commands: Commands,
+    particle: Res<ParticleHandle<DustTrailParticle>>,
+    pause: Res<State<Pause>>,
+) {
+    if pause.get().0 {
+        return;
+    }
+
+    let entity = event.entity;
+    let Ok((transform, mut facing, mut animation_state, invulnerability)) =
+        player_query.get_mut(entity)
+    else {
+        return;
+    };
+    let Some(direction) = event.direction.try_normalize() else {
+        return;
+    };
+    if animation_state.0.0 == AnimationAction::Jump {
+        return;
+    }
+
+    facing.0 = direction;
+    // NOTE: Using try here is necessary since the entity might have been despawned elsewhere.
+    commands.entity(entity).try_insert((
+        DashMotion {
+            direction,
+            ..default()
+        },
+        DashTimer::default(),
+        DashCooldownTimer::default(),
+    ));
+    // NOTE: This does not shorten a longer invulnerability, e.g. from `HitReaction`.
+    if invulnerability.is_none_or(|t| t.remaining_secs() < DASH_DURATION_SECS) {
+        commands
+            .entity(entity)
+            .try_insert(InvulnerabilityTimer(Timer::from_seconds(
+                DASH_DURATION_SECS,
+                TimerMode::Once,
+            )));
+    }
+    animation_state.set_new_action(AnimationAction::Dash);
+
+    commands.trigger(SpawnParticleOnce::<DustTrailParticle>::new(
+        transform.translation.xy().extend(OVERLAY_Z),
+        particle.handle.clone(),
+    ));
+}
+
 /// On a fired [`Melee`], write [`InitAttack`].
 pub(super) fn init_melee_attack(
     _: On<Fire<Melee>>,
     mut writer: MessageWriter<InitAttack>,
-    player: Single<(Entity, &AttackStats, Option<&AttackTimer>), With<Player>>,
+    player: Single<
+        (Entity, &AttackStats, Option<&AttackTimer>),
+        (With<Player>, Without<HitStunTimer>),
+    >,
     mut commands: Commands,
     pause: Res<State<Pause>>,
 ) {
@@ -143,7 +269,7 @@ pub(super) fn init_melee_attack(
     {
         return;
     }
-    let Some(melee) = &stats.melee else {
+    let Some(melee) = stats.melee() else {
         warn_once!("{}", WARN_INVALID_ATTACK_DATA);
         return;
     };
@@ -154,3 +280,48 @@ pub(super) fn init_melee_attack(
     });
     writer.write(InitAttack::Melee(entity));
 }
+
+/// On a fired [`Ranged`], write [`InitAttack`].
+pub(super) fn init_ranged_attack(
+    _: On<Fire<Ranged>>,
+    mut writer: MessageWriter<InitAttack>,
+    player: Single<
+        (Entity, &AttackStats, Option<&AttackTimer>),
+        (With<Player>, Without<HitStunTimer>),
+    >,
+    mut commands: Commands,
+    pause: Res<State<Pause>>,
+) {
+    if pause.get().0 {
+        return;
+    }
+    let (entity, stats, timer) = *player;
+    if let Some(timer) = timer
+        && !timer.0.is_finished()
+    {
+        return;
+    }
+    let Some(ranged) = stats.ranged() else {
+        warn_once!("{}", WARN_INVALID_ATTACK_DATA);
+        return;
+    };
+
+    commands.trigger(DelayAttack {
+        entity,
+        cooldown_secs: ranged.cooldown_secs,
+    });
+    writer.write(InitAttack::Ranged(entity));
+}
+
+/// On a started [`CycleAttack`], cycle the attacks in [`AttackStats`].
+pub(super) fn cycle_attack(
+    _: On<Start<CycleAttack>>,
+    mut stats: Single<&mut AttackStats, With<Player>>,
+    pause: Res<State<Pause>>,
+) {
+    if pause.get().0 {
+        return;
+    }
+
+    stats.cycle();
+}
@@ -44,6 +44,50 @@ pub(super) fn mock_jump_from_touch(
     }
 }
 
+/// Trigger [`StartDash`] along [`Swipe::dash_swipe`] from [`Touches`].
+///
+/// This does not mock [`Dash`] since that dashes in the [`FacingDirection`].
+pub(super) fn mock_dash_from_touch(
+    dash: Single<Entity, With<Player>>,
+    mut commands: Commands,
+    pointer_blocked: Res<PointerBlockedByUi>,
+    touches: Res<Touches>,
+) {
+    for touch in touches.iter_just_released() {
+        if pointer_blocked.0.contains(&Some(touch.id())) {
+            continue;
+        }
+        if let Some(direction) = touch.dash_swipe() {
+            commands.trigger(StartDash {
+                entity: *dash,
+                direction,
+            });
+        }
+    }
+}
+
+/// Trigger [`StartDash`] along [`Swipe::dash_swipe`] from [`MouseDrag`].
+///
+/// This does not mock [`Dash`] since that dashes in the [`FacingDirection`].
+pub(super) fn mock_dash_from_drag(
+    dash: Single<Entity, With<Player>>,
+    mut commands: Commands,
+    drag: Res<MouseDrag>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    pointer_blocked: Res<PointerBlockedByUi>,
+) {
+    if pointer_blocked.0.contains(&None) || !mouse.just_released(MouseButton::Left) {
+        return;
+    }
+
+    if let Some(direction) = drag.dash_swipe() {
+        commands.trigger(StartDash {
+            entity: *dash,
+            direction,
+        });
+    }
+}
+
 /// Mock [`Melee`] from [`Touches`].
 pub(super) fn mock_melee_from_touch(
     melee: Single<Entity, With<Player>>,
@@ -57,10 +101,11 @@ pub(super) fn mock_melee_from_touch(
         return;
     }
 
-    if touches
-        .iter_just_released()
-        .any(|t| !pointer_blocked.0.contains(&Some(t.id())) && !t.is_vertical_swipe())
-    {
+    if touches.iter_just_released().any(|t| {
+        !pointer_blocked.0.contains(&Some(t.id()))
+            && !t.is_vertical_swipe()
+            && t.dash_swipe().is_none()
+    }) {
         commands
             .entity(*melee)
             .mock_once::<Player, Melee>(TriggerState::Fired, true);
@@ -110,6 +155,7 @@ pub(super) fn mock_melee_from_click(
         || !mouse.just_released(MouseButton::Left)
         || !pointer_start.is_tap(time.elapsed_secs())
         || drag.is_vertical_swipe()
+        || drag.dash_swipe().is_some()
     {
         return;
     }
@@ -119,6 +165,22 @@ pub(super) fn mock_melee_from_click(
         .mock_once::<Player, Melee>(TriggerState::Fired, true);
 }
 
+/// Mock [`Ranged`] from right clicks.
+pub(super) fn mock_ranged_from_click(
+    ranged: Single<Entity, With<Player>>,
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    pointer_blocked: Res<PointerBlockedByUi>,
+) {
+    if pointer_blocked.0.contains(&None) || !mouse.just_released(MouseButton::Right) {
+        return;
+    }
+
+    commands
+        .entity(*ranged)
+        .mock_once::<Player, Ranged>(TriggerState::Fired, true);
+}
+
 /// Mock [`Aim`] from clicks.
 pub(super) fn mock_aim_from_click(
     aim: Single<Entity, With<Player>>,
@@ -129,8 +191,10 @@ pub(super) fn mock_aim_from_click(
     mouse: Res<ButtonInput<MouseButton>>,
     pointer_blocked: Res<PointerBlockedByUi>,
 ) {
-    // NOTE: We are using `just_pressed` to allow use in `Melee`.
-    if pointer_blocked.0.contains(&None) || !mouse.just_pressed(MouseButton::Left) {
+    // NOTE: We are using `just_pressed` to allow use in `Melee` and `Ranged`.
+    if pointer_blocked.0.contains(&None)
+        || !mouse.any_just_pressed([MouseButton::Left, MouseButton::Right])
+    {
         return;
     }
 
@@ -148,11 +212,13 @@ pub(super) fn mock_aim_from_click(
     }
 }
 
-/// On a completed [`Melee`], reset [`ActionMock`] for [`Aim`].
-pub(super) fn reset_aim_mock(
-    _: On<Complete<Melee>>,
+/// On a completed attack action `T`, reset [`ActionMock`] for [`Aim`].
+pub(super) fn reset_aim_mock<T>(
+    _: On<Complete<T>>,
     mock: Single<&mut ActionMock, With<Action<Aim>>>,
-) {
+) where
+    T: InputAction,
+{
     let mut mock = mock.into_inner();
     mock.enabled = false;
 }
@@ -7,10 +7,17 @@ use bevy::{
 /// Threshold for a valid swipe action from touch input in logical pixels.
 const SWIPE_THRESHOLD: f32 = 50.;
 
+/// Threshold for a swipe that dashes in logical pixels.
+const DASH_SWIPE_THRESHOLD: f32 = 120.;
+
 /// Trait for determining if input is a swipe.
 pub(crate) trait Swipe {
     fn is_vertical_swipe(&self) -> bool;
     fn is_swipe_up(&self) -> bool;
+    /// Direction in world space of a swipe that is long enough to dash.
+    ///
+    /// Swipes up are excluded since they are used for jumping.
+    fn dash_swipe(&self) -> Option<Vec2>;
 }
 impl Swipe for Touch {
     fn is_vertical_swipe(&self) -> bool {
@@ -21,6 +28,16 @@ impl Swipe for Touch {
         // NOTE: We are inverting y to align with user intent because `distance` is reversed on the y axis.
         self.is_vertical_swipe() && self.distance().y < 0.
     }
+    fn dash_swipe(&self) -> Option<Vec2> {
+        dash_direction(self.distance()).filter(|_| !self.is_swipe_up())
+    }
+}
+
+/// Direction in world space of a swipe over `distance` if it exceeds [`DASH_SWIPE_THRESHOLD`].
+fn dash_direction(distance: Vec2) -> Option<Vec2> {
+    // NOTE: We are inverting y to align with world space because `distance` is reversed on the y axis.
+    (distance.length() > DASH_SWIPE_THRESHOLD)
+        .then(|| Vec2::new(distance.x, -distance.y).normalize())
 }
 
 /// Max duration for a tap to be recognized.
@@ -56,6 +73,11 @@ impl Swipe for MouseDrag {
         self.distance()
             .is_some_and(|d| self.is_vertical_swipe() && d.y < 0.)
     }
+    fn dash_swipe(&self) -> Option<Vec2> {
+        self.distance()
+            .and_then(dash_direction)
+            .filter(|_| !self.is_swipe_up())
+    }
 }
 
 /// Update info in [`PointerStartTimeSecs`].
@@ -1,11 +1,30 @@
 //! Game worlds
 
+mod interior;
+mod maps;
 mod navmesh;
 mod overworld;
+mod pickups;
+mod props;
+mod transitions;
 
 pub(crate) mod prelude {
-    pub(crate) use super::overworld::{Overworld, OverworldAssets, OverworldProcGen};
-    pub(crate) use super::{Level, LevelAssets, LevelDimensions, LevelRng, impl_level_assets};
+    pub(crate) use super::interior::{Interior, InteriorAssets};
+    pub(crate) use super::maps::{
+        Map, MapData, MapDataCache, MapHandle, MapLayout, MapObject, MapObjectKind, MapTile,
+    };
+    pub(crate) use super::overworld::{
+        Overworld, OverworldAssets, OverworldProcGen, RespawnPlayer,
+    };
+    pub(crate) use super::pickups::{
+        PICKUP_HEAL_AMOUNT, PICKUP_MAX_HEALTH_AMOUNT, PICKUP_MAX_HEALTH_CHANCE, Pickup, PickupKind,
+    };
+    pub(crate) use super::props::{Prop, PropData, PropDataCache, PropHandle, PropKind};
+    pub(crate) use super::transitions::{Door, DoorOccupied, LevelReturn, RespawnPoint};
+    pub(crate) use super::{
+        ActiveLevel, EnterLevelSystems, Level, LevelAssets, LevelDimensions, LevelRng,
+        impl_level_assets,
+    };
 }
 
 use std::marker::PhantomData;
@@ -13,21 +32,99 @@ use std::marker::PhantomData;
 use bevy::{prelude::*, reflect::Reflectable};
 use bevy_asset_loader::asset_collection::AssetCollection;
 
-use crate::{procgen::prelude::*, screens::prelude::*, utils::prelude::*};
+use crate::{
+    core::prelude::*, images::prelude::*, procgen::prelude::*, screens::prelude::*,
+    utils::prelude::*,
+};
 
 pub(super) struct LevelsPlugin;
 impl Plugin for LevelsPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(navmesh::NavMeshPlugin);
 
+        app.add_sub_state::<ActiveLevel>();
+
+        // NOTE: Props are y-sorted by their collider instead of their sprite size.
+        app.init_resource::<CelSize<Prop>>();
+
+        for level in [ActiveLevel::Overworld, ActiveLevel::Interior] {
+            app.configure_sets(
+                OnEnter(level),
+                (
+                    EnterLevelSystems::Levels,
+                    EnterLevelSystems::Camera,
+                    EnterLevelSystems::NavMesh,
+                )
+                    .chain(),
+            );
+        }
+
         app.add_systems(Startup, setup_rng::<LevelRng>);
+        app.add_systems(
+            OnEnter(Screen::Gameplay),
+            seed_rng::<LevelRng>
+                .after(EnterGameplaySystems::LoadSave)
+                .before(EnterGameplaySystems::Levels),
+        );
         app.add_systems(
             OnEnter(Screen::Gameplay),
             overworld::spawn_overworld.in_set(EnterGameplaySystems::Levels),
         );
+        app.add_systems(
+            OnEnter(ActiveLevel::Overworld),
+            overworld::return_to_overworld
+                .run_if(resource_exists::<LevelReturn>)
+                .in_set(EnterLevelSystems::Levels),
+        );
+        app.add_systems(
+            OnEnter(ActiveLevel::Interior),
+            interior::spawn_interior.in_set(EnterLevelSystems::Levels),
+        );
+        // NOTE: These only run on level transitions. When exiting `Screen::Gameplay`, the state has
+        //       already changed and the level is despawned together with the player instead.
+        app.add_systems(
+            OnExit(ActiveLevel::Overworld),
+            (
+                transitions::store_level_return,
+                transitions::leave_level::<Overworld>,
+            )
+                .chain()
+                .run_if(in_state(Screen::Gameplay)),
+        );
+        app.add_systems(
+            OnExit(ActiveLevel::Interior),
+            transitions::leave_level::<Interior>.run_if(in_state(Screen::Gameplay)),
+        );
+        app.add_observer(overworld::on_respawn_player);
+        app.add_systems(
+            Update,
+            (transitions::enter_doors, pickups::collect_pickups)
+                .run_if(in_state(Screen::Gameplay))
+                .in_set(AppSystems::Update)
+                .in_set(PausableSystems),
+        );
     }
 }
 
+/// Currently active [`Level`].
+///
+/// The overworld is always active when entering [`Screen::Gameplay`].
+#[derive(SubStates, Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[source(Screen = Screen::Gameplay)]
+pub(crate) enum ActiveLevel {
+    #[default]
+    Overworld,
+    Interior,
+}
+
+/// A [`SystemSet`] for systems that initialize an [`ActiveLevel`].
+#[derive(SystemSet, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub(crate) enum EnterLevelSystems {
+    Levels,
+    Camera,
+    NavMesh,
+}
+
 /// Applies to anything that is a level
 pub(crate) trait Level
 where
@@ -58,7 +155,7 @@ macro_rules! impl_level_assets {
 pub(crate) use impl_level_assets;
 
 /// Rng for levels
-#[derive(Component, Default)]
+#[derive(Component, Default, TypePath)]
 pub(crate) struct LevelRng;
 impl ForkedRng for LevelRng {}
 
@@ -0,0 +1,163 @@
+//! Interior-specific behavior.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+use bevy_prng::WyRand;
+use bevy_rapier2d::prelude::*;
+use rand::seq::IndexedRandom as _;
+
+use crate::{
+    audio::prelude::*, characters::prelude::*, levels::prelude::*, log::prelude::*,
+    render::prelude::*, screens::prelude::*,
+};
+
+/// Assets for interiors
+#[derive(AssetCollection, Resource, Default, Reflect)]
+pub(crate) struct InteriorAssets {
+    #[asset(key = "interior.music", collection(typed), optional)]
+    music: Option<Vec<Handle<AudioSource>>>,
+
+    #[asset(key = "interior.tile_set")]
+    pub(crate) tile_set: Handle<Image>,
+}
+impl_level_assets!(InteriorAssets);
+
+/// Interior marker
+#[derive(Component, Default, Reflect)]
+pub(crate) struct Interior;
+impl Level for Interior {}
+
+/// Level position
+const LEVEL_POS: Vec3 = Vec3::new(0., 0., LEVEL_Z);
+
+/// Spawn interior from [`MapDataCache`] and move the player to it
+pub(super) fn spawn_interior(
+    player: Single<(Entity, &mut Transform), With<Player>>,
+    mut level_rng: Single<&mut WyRand, With<LevelRng>>,
+    mut commands: Commands,
+    assets: Res<InteriorAssets>,
+    images: Res<Assets<Image>>,
+    light_mesh: Res<LightMeshHandle<StreetLight>>,
+    mist_mesh: Res<MistMeshHandle<StandardMist>>,
+    map_data: Res<MapDataCache<Interior>>,
+) {
+    let map = &map_data.map;
+    let level = commands
+        .spawn((
+            Name::new("Level"),
+            Interior,
+            Transform::from_translation(LEVEL_POS),
+            DespawnOnExit(Screen::Gameplay),
+            Visibility::default(),
+        ))
+        .id();
+
+    // Spawn tiles for each layer
+    let image = assets.tile_set().clone();
+    let columns = images.get(&image).expect(ERR_INVALID_IMAGE).width() / map.tile_size as u32;
+    for layer in 0..map.layers.len() {
+        let entity = commands.spawn(Name::new("Tiles")).id();
+        map.spawn_layer(
+            &mut commands,
+            entity,
+            layer,
+            image.clone(),
+            columns,
+            Transform::from_xyz(0., 0., LAYER_Z_DELTA * layer as f32),
+        );
+        commands.entity(level).add_child(entity);
+    }
+
+    // Spawn walls around the map
+    // NOTE: Tiles are centered on their position, so the map starts half a tile before the origin.
+    let tile_size = map.tile_size;
+    let map_size = map.size.as_vec2() * tile_size;
+    let center = (map_size - tile_size) / 2.;
+    for (offset, half_size) in [
+        (
+            Vec2::new(0., -map_size.y),
+            Vec2::new(map_size.x, tile_size) / 2.,
+        ),
+        (
+            Vec2::new(0., map_size.y),
+            Vec2::new(map_size.x, tile_size) / 2.,
+        ),
+        (
+            Vec2::new(-map_size.x, 0.),
+            Vec2::new(tile_size, map_size.y) / 2.,
+        ),
+        (
+            Vec2::new(map_size.x, 0.),
+            Vec2::new(tile_size, map_size.y) / 2.,
+        ),
+    ] {
+        let pos = center + (offset + offset.signum() * tile_size) / 2.;
+        commands.entity(level).with_child((
+            Name::new("Wall"),
+            Transform::from_translation(pos.extend(0.)),
+            RigidBody::Fixed,
+            Collider::cuboid(half_size.x, half_size.y),
+        ));
+    }
+
+    // Spawn objects
+    for object in &map.objects {
+        let pos = map.world_pos(object.pos.into());
+        match object.kind {
+            // NOTE: The player already exists and is moved below.
+            MapObjectKind::Player => {}
+            MapObjectKind::Slime => {
+                let entity = commands.spawn(Slime).id();
+                commands.trigger(SpawnCharacter::<Slime, Interior> {
+                    entity,
+                    pos,
+                    health: None,
+                    max_health: None,
+                    _phantom: PhantomData,
+                });
+            }
+            // NOTE: Boss fights need an arena of procedurally generated chunks, so bosses are only
+            //       placed by stamps in the overworld.
+            MapObjectKind::Boss => {}
+            MapObjectKind::StreetLight => {
+                let entity = StreetLight::new(light_mesh.handle.clone()).spawn(&mut commands, pos);
+                commands.entity(level).add_child(entity);
+            }
+            MapObjectKind::StandardMist => {
+                let entity = StandardMist::new(mist_mesh.handle.clone()).spawn(&mut commands, pos);
+                commands.entity(level).add_child(entity);
+            }
+            MapObjectKind::Door => {
+                commands.entity(level).with_child((
+                    Name::new("Door"),
+                    Door(ActiveLevel::Overworld),
+                    Door::sprite(Vec2::splat(tile_size)),
+                    Transform::from_translation(pos.extend(FOREGROUND_Z)),
+                ));
+            }
+        }
+    }
+
+    // Spawn music
+    let music_handles = assets.music().clone().unwrap_or_else(|| {
+        warn_once!("{}", WARN_INCOMPLETE_ASSET_DATA);
+        Vec::default()
+    });
+    if let Some(handle) = music_handles.choose(&mut level_rng).cloned() {
+        commands
+            .entity(level)
+            .with_child((Name::new("Gameplay Music"), music(handle)));
+    }
+
+    // Move player to interior
+    let (entity, mut transform) = player.into_inner();
+    let pos = map
+        .positions(MapObjectKind::Player)
+        .first()
+        .map_or(Vec2::ZERO, |pos| map.world_pos(*pos));
+    transform.translation.x = pos.x;
+    transform.translation.y = pos.y;
+    commands.entity(level).add_child(entity);
+}
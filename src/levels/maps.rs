@@ -0,0 +1,210 @@
+//! Hand-authored maps.
+
+use std::{collections::HashMap, marker::PhantomData};
+
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use serde::Deserialize;
+
+use crate::{images::prelude::*, levels::prelude::*, log::prelude::*, procgen::prelude::*};
+
+/// Tile in a [`MapLayout`] referencing a set in [`TileData`] by its field name.
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct MapTile {
+    pub(crate) set: String,
+    #[serde(default)]
+    pub(crate) index: usize,
+}
+
+/// Kind of object that is spawned at a [`MapObject`].
+#[derive(Deserialize, Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum MapObjectKind {
+    Player,
+    Slime,
+    /// Boss of the character type that is spawned in the chunk.
+    Boss,
+    StreetLight,
+    StandardMist,
+    Door,
+}
+
+/// Object spawn point in a [`MapLayout`].
+///
+/// `pos` is the tile position from the bottom left.
+#[derive(Deserialize, Copy, Clone, Debug)]
+pub(crate) struct MapObject {
+    pub(crate) kind: MapObjectKind,
+    pub(crate) pos: (u32, u32),
+}
+
+/// Hand-authored layout deserialized from a ron file.
+///
+/// Each layer contains rows ordered from top to bottom. Each character is looked up in `legend`.
+/// Characters missing from `legend` are left empty.
+#[derive(Deserialize, Clone, Debug, Default)]
+pub(crate) struct MapLayout {
+    pub(crate) layers: Vec<Vec<String>>,
+    pub(crate) legend: HashMap<char, MapTile>,
+    #[serde(default)]
+    pub(crate) objects: Vec<MapObject>,
+}
+impl MapLayout {
+    /// [`Map`] with tiles resolved from `tile_data`.
+    ///
+    /// Warns if a [`MapTile`] does not exist in `tile_data`.
+    pub(crate) fn resolve<T>(&self, tile_data: &TileDataCache<T>) -> Map
+    where
+        T: ProcGenerated,
+    {
+        let width = self
+            .layers
+            .iter()
+            .flatten()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        let height = self.layers.iter().map(Vec::len).max().unwrap_or(0);
+        let tile = |c: char| {
+            let tile = self.legend.get(&c)?;
+            let coords = tile_data.set(&tile.set).and_then(|s| s.get(tile.index));
+            if coords.is_none() {
+                warn_once!("{}", WARN_INVALID_MAP_DATA);
+            }
+            coords.copied()
+        };
+
+        // NOTE: Rows are authored from top to bottom, but tiles are stored from the bottom.
+        let layers = self
+            .layers
+            .iter()
+            .map(|rows| {
+                (0..height)
+                    .rev()
+                    .flat_map(|y| {
+                        let mut chars = rows.get(y).map(|r| r.chars()).into_iter().flatten();
+                        (0..width).map(move |_| chars.next())
+                    })
+                    .map(|c| c.and_then(tile))
+                    .collect()
+            })
+            .collect();
+
+        Map {
+            tile_size: tile_data.tile_size,
+            size: UVec2::new(width as u32, height as u32),
+            layers,
+            objects: self.objects.clone(),
+        }
+    }
+}
+
+/// [`MapLayout`] with resolved tiles.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Map {
+    pub(crate) tile_size: f32,
+    /// Size of the map in tiles.
+    pub(crate) size: UVec2,
+    /// Tile coordinates in the tile set per layer ordered by rows from the bottom left.
+    pub(crate) layers: Vec<Vec<Option<(usize, usize)>>>,
+    pub(crate) objects: Vec<MapObject>,
+}
+impl Map {
+    /// Tile coordinates in the tile set at tile position `pos` of `layer`.
+    pub(crate) fn tile(&self, layer: usize, pos: UVec2) -> Option<(usize, usize)> {
+        if pos.x >= self.size.x || pos.y >= self.size.y {
+            return None;
+        }
+        self.layers
+            .get(layer)?
+            .get((pos.y * self.size.x + pos.x) as usize)
+            .copied()
+            .flatten()
+    }
+
+    /// Tile positions of [`MapObject`]s of `kind`.
+    pub(crate) fn positions(&self, kind: MapObjectKind) -> Vec<UVec2> {
+        self.objects
+            .iter()
+            .filter(|o| o.kind == kind)
+            .map(|o| o.pos.into())
+            .collect()
+    }
+
+    /// World position of the center of the tile at tile position `pos`.
+    pub(crate) fn world_pos(&self, pos: UVec2) -> Vec2 {
+        pos.as_vec2() * self.tile_size
+    }
+
+    /// Spawn tiles of `layer` as children of `container` and insert [`TilemapBundle`].
+    ///
+    /// `columns` is the number of columns in the tile set `image`.
+    pub(crate) fn spawn_layer(
+        &self,
+        commands: &mut Commands,
+        container: Entity,
+        layer: usize,
+        image: Handle<Image>,
+        columns: u32,
+        transform: Transform,
+    ) {
+        let mut storage = TileStorage::empty(self.size.into());
+        for y in 0..self.size.y {
+            for x in 0..self.size.x {
+                let tile_pos = TilePos { x, y };
+                let Some((tile_x, tile_y)) = self.tile(layer, UVec2::new(x, y)) else {
+                    continue;
+                };
+                let entity = commands
+                    .spawn(TileBundle {
+                        position: tile_pos,
+                        texture_index: TileTextureIndex(tile_y as u32 * columns + tile_x as u32),
+                        tilemap_id: TilemapId(container),
+                        ..default()
+                    })
+                    .id();
+                commands.entity(container).add_child(entity);
+                storage.set(&tile_pos, entity);
+            }
+        }
+
+        let tile_size_vec = Vec2::splat(self.tile_size);
+        commands.entity(container).insert(TilemapBundle {
+            grid_size: tile_size_vec.into(),
+            size: self.size.into(),
+            storage,
+            texture: TilemapTexture::Single(image),
+            tile_size: tile_size_vec.into(),
+            transform,
+            ..default()
+        });
+    }
+}
+
+/// Map data deserialized from a ron file.
+#[derive(Deserialize, Asset, TypePath, Default)]
+pub(crate) struct MapData<T>
+where
+    T: Level,
+{
+    pub(crate) map: MapLayout,
+    #[serde(skip)]
+    pub(crate) _phantom: PhantomData<T>,
+}
+
+/// Handle for [`MapData`].
+#[derive(Resource)]
+pub(crate) struct MapHandle<T>(pub(crate) Handle<MapData<T>>)
+where
+    T: Level;
+
+/// Cache for [`MapData`]
+///
+/// This is to allow easier access.
+#[derive(Resource, Default)]
+pub(crate) struct MapDataCache<T>
+where
+    T: Level,
+{
+    pub(crate) map: Map,
+    pub(crate) _phantom: PhantomData<T>,
+}
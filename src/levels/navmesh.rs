@@ -21,72 +21,116 @@ impl Plugin for NavMeshPlugin {
             OnEnter(Screen::Gameplay),
             spawn_navmesh::<OverworldProcGen, Overworld>.in_set(EnterGameplaySystems::NavMesh),
         );
+        app.add_systems(
+            OnEnter(ActiveLevel::Overworld),
+            spawn_navmesh::<OverworldProcGen, Overworld>
+                .run_if(not(any_with_component::<NavMeshSettings>))
+                .in_set(EnterLevelSystems::NavMesh),
+        );
+        app.add_systems(
+            OnEnter(ActiveLevel::Interior),
+            spawn_map_navmesh::<Interior>.in_set(EnterLevelSystems::NavMesh),
+        );
         app.add_systems(
             OnEnter(ProcGenState::MoveNavMesh),
-            move_navmesh::<OverworldProcGen>.run_if(in_state(Screen::Gameplay)),
+            move_navmesh::<OverworldProcGen>.run_if(in_state(ActiveLevel::Overworld)),
         );
     }
 }
 
-/// Number of horizontal/vertical chunks in a straight line
-const NUM_CHUNKS: u32 = PROCGEN_DISTANCE as u32 * 2 + 1;
-/// Size of the [`ManagedNavMesh`]
-const NAVMESH_SIZE: UVec2 = UVec2::new(CHUNK_SIZE.x * NUM_CHUNKS, CHUNK_SIZE.y * NUM_CHUNKS);
+/// Size of the [`ManagedNavMesh`] in tiles from [`ProcGenDataCache`].
+fn navmesh_size<T>(config: &ProcGenDataCache<T>) -> UVec2
+where
+    T: ProcGenerated,
+{
+    config.chunk_size * config.num_chunks()
+}
 
 /// Spawn [`ManagedNavMesh`] from [`NavMeshSettings`]
 pub(crate) fn spawn_navmesh<T, A>(
     level: Single<Entity, With<A>>,
     mut commands: Commands,
+    config: Res<ProcGenDataCache<T>>,
     tile_data: Res<TileDataCache<T>>,
 ) where
     T: ProcGenerated,
     A: Level,
 {
+    let (chunk_size, navmesh_size) = (config.chunk_size, navmesh_size(&config));
     let tile_size = tile_data.tile_size;
     // NOTE: This is anchored to the bottom left. This means that we have to offset it by:
-    //       (`NAVMESH_SIZE` + (one chunk - 1 tile)) / 2 as world pos.
+    //       (`navmesh_size` + (one chunk - 1 tile)) / 2 as world pos.
     //       This seemingly weird calculation is in part due to the chunk spawning at `0,0` having its
     //       minimum tile centered at `0,0`, not the chunk itself.
-    //       Otherwise we would only have to offset it by: `NAVMESH_SIZE` / 2 as world pos.
-    let target_pos = (-NAVMESH_SIZE.as_vec2() + (CHUNK_SIZE.as_vec2() - 1.)) * tile_size / 2.;
+    //       Otherwise we would only have to offset it by: `navmesh_size` / 2 as world pos.
+    let target_pos = (-navmesh_size.as_vec2() + (chunk_size.as_vec2() - 1.)) * tile_size / 2.;
 
     commands.entity(*level).with_child((
-        NavMeshSettings {
-            simplify: 0.05,
-            merge_steps: 1,
-            fixed: Triangulation::from_outer_edges(&[
-                Vec2::ZERO,
-                Vec2::new(NAVMESH_SIZE.x as f32, 0.),
-                NAVMESH_SIZE.as_vec2(),
-                Vec2::new(0., NAVMESH_SIZE.y as f32),
-            ]),
-            ..default()
-        },
+        navmesh_settings(navmesh_size),
         // NOTE: We have to use `OnDemand` since without any obstacles, the other modes never execute.
         NavMeshUpdateMode::OnDemand(false),
         Transform::from_translation(target_pos.extend(0.)).with_scale(Vec3::splat(tile_size)),
     ));
 }
 
+/// Spawn [`ManagedNavMesh`] covering the [`Map`] of [`Level`] `L`.
+///
+/// The walls around the [`Map`] are its outer edges, so navigators can not leave it.
+pub(crate) fn spawn_map_navmesh<L>(
+    level: Single<Entity, With<L>>,
+    mut commands: Commands,
+    map_data: Res<MapDataCache<L>>,
+) where
+    L: Level,
+{
+    let map = &map_data.map;
+    // NOTE: Tiles are centered on their position, so the map starts half a tile below the origin.
+    let target_pos = Vec2::splat(-map.tile_size / 2.);
+
+    commands.entity(*level).with_child((
+        navmesh_settings(map.size),
+        // NOTE: The map does not move, so this only has to be built once.
+        NavMeshUpdateMode::OnDemand(true),
+        Transform::from_translation(target_pos.extend(0.)).with_scale(Vec3::splat(map.tile_size)),
+    ));
+}
+
+/// [`NavMeshSettings`] for a [`ManagedNavMesh`] with a size of `size` in tiles.
+fn navmesh_settings(size: UVec2) -> NavMeshSettings {
+    NavMeshSettings {
+        simplify: 0.05,
+        merge_steps: 1,
+        fixed: Triangulation::from_outer_edges(&[
+            Vec2::ZERO,
+            Vec2::new(size.x as f32, 0.),
+            size.as_vec2(),
+            Vec2::new(0., size.y as f32),
+        ]),
+        ..default()
+    }
+}
+
 /// Move [`ManagedNavMesh`] with generated chunks
 pub(crate) fn move_navmesh<T>(
     navmesh: Single<(&mut Transform, &mut NavMeshUpdateMode), With<ManagedNavMesh>>,
     cache: Res<ProcGenCache<T>>,
     mut next_init_state: ResMut<NextState<ProcGenInit>>,
     mut next_state: ResMut<NextState<ProcGenState>>,
+    config: Res<ProcGenDataCache<T>>,
     tile_data: Res<TileDataCache<T>>,
 ) where
     T: ProcGenerated,
 {
+    let (chunk_size, navmesh_size) = (config.chunk_size, navmesh_size(&config));
     let tile_size = tile_data.tile_size;
     // Change navmesh translation
-    let min_world_pos = cache.min_chunk_pos().as_vec2() * CHUNK_SIZE.as_vec2() * tile_size;
+    let min_world_pos = cache.min_chunk_pos().as_vec2() * chunk_size.as_vec2() * tile_size;
     // NOTE: This is anchored to the bottom left. Instead of min world pos, we actually need the minimum tile of the center chunk.
-    //       Therefore we are adding `CHUNK_SIZE` * `PROCGEN_DISTANCE` to the calculation from `spawn_navmesh`
+    //       Therefore we are adding `chunk_size` * `procgen_distance` to the calculation from `spawn_navmesh`
     //       and then adding everything to world pos to get the correct offset as world pos.
     let target_pos = (min_world_pos
-        + ((-NAVMESH_SIZE.as_vec2() + (CHUNK_SIZE.as_vec2() - 1.)) / 2.
-            + CHUNK_SIZE.as_vec2() * PROCGEN_DISTANCE as f32)
+        + ((-navmesh_size.as_vec2() + (chunk_size.as_vec2() - 1.)) / 2.
+            + chunk_size.as_vec2() * config.procgen_distance as f32)
             * tile_size)
         .floor();
     // Set translation and update navmesh
@@ -4,12 +4,11 @@ use std::marker::PhantomData;
 
 use bevy::prelude::*;
 use bevy_asset_loader::prelude::*;
-use bevy_prng::WyRand;
-use rand::seq::IndexedRandom;
+use bevy_rapier2d::prelude::*;
 
 use crate::{
-    audio::prelude::*, characters::prelude::*, levels::prelude::*, log::prelude::*,
-    procgen::prelude::*, render::prelude::*, screens::prelude::*,
+    characters::prelude::*, levels::prelude::*, log::prelude::*, physics::prelude::*,
+    procgen::prelude::*, render::prelude::*, save::prelude::*, screens::prelude::*,
 };
 
 /// Assets for the overworld
@@ -29,7 +28,7 @@ pub(crate) struct Overworld;
 impl Level for Overworld {}
 
 /// Marker component for overworld procedural generation
-#[derive(Component, Default, Reflect)]
+#[derive(Component, Default, Reflect, Clone)]
 pub(crate) struct OverworldProcGen;
 impl ProcGenerated for OverworldProcGen {}
 
@@ -39,43 +38,176 @@ const LEVEL_POS: Vec3 = Vec3::new(0., 0., LEVEL_Z);
 /// Player position
 const PLAYER_POS: Vec2 = Vec2::new(0., 0.);
 
+/// Distance in px to [`Npc`]s that a respawn position has to keep.
+const RESPAWN_NPC_DISTANCE: f32 = 64.;
+
+/// Distance in px between candidate respawn positions around the [`RespawnPoint`].
+const RESPAWN_SEARCH_STEP: f32 = 16.;
+
+/// Number of square rings of candidate respawn positions around the [`RespawnPoint`].
+const RESPAWN_SEARCH_RINGS: i32 = 4;
+
+/// [`Event`] that respawns the [`Player`] in the overworld.
+#[derive(Event)]
+pub(crate) struct RespawnPlayer;
+
+/// Overworld level bundle
+fn level_bundle() -> impl Bundle {
+    (
+        Name::new("Level"),
+        Overworld,
+        Transform::from_translation(LEVEL_POS),
+        DespawnOnExit(Screen::Gameplay),
+        Visibility::default(),
+    )
+}
+
 /// Spawn overworld with player, enemies and objects
 pub(super) fn spawn_overworld(
-    mut level_rng: Single<&mut WyRand, With<LevelRng>>,
     mut commands: Commands,
-    level_assets: Res<OverworldAssets>,
+    item_data: Res<ItemDataCache<Player>>,
+    save: Option<Res<LoadedSave>>,
 ) {
-    let level = commands
-        .spawn((
-            Name::new("Level"),
-            Overworld,
-            Transform::from_translation(LEVEL_POS),
-            DespawnOnExit(Screen::Gameplay),
-            Visibility::default(),
-        ))
-        .id();
+    commands.spawn(level_bundle());
 
-    // Spawn music
-    if let Some(level_music) = level_assets
-        .music()
-        .clone()
-        .unwrap_or_else(|| {
-            warn_once!("{}", WARN_INCOMPLETE_ASSET_DATA);
-            Vec::default()
-        })
-        .choose(&mut level_rng)
-        .cloned()
-    {
-        commands.entity(level).with_children(|commands| {
-            commands.spawn((Name::new("Gameplay Music"), music(level_music)));
-        });
-    }
-
-    // Spawn player
-    let entity = commands.spawn(Player).id();
+    // Spawn player from save if it exists
+    let (pos, health, max_health) = save.as_ref().map_or((PLAYER_POS, None, None), |s| {
+        (
+            s.0.player_pos,
+            Some(s.0.player_health),
+            Some(s.0.player_max_health),
+        )
+    });
+    // NOTE: These are not part of the character, so they are kept on respawn.
+    let (inventory, equipment) = save.as_ref().map_or_else(
+        || (item_data.starting_inventory(), Equipment::default()),
+        |s| saved_items(&s.0, &item_data),
+    );
+    let experience = save.map_or_else(Experience::default, |s| Experience {
+        level: s.0.player_level,
+        points: s.0.player_experience,
+        ..default()
+    });
+    let entity = commands
+        .spawn((Player, inventory, equipment, experience))
+        .id();
+    commands.insert_resource(RespawnPoint(pos));
     commands.trigger(SpawnCharacter::<Player, Overworld> {
         entity,
-        pos: PLAYER_POS,
+        pos,
+        health,
+        max_health,
         _phantom: PhantomData,
     });
 }
+
+/// Spawn overworld and move the player to [`LevelReturn`]
+pub(super) fn return_to_overworld(
+    player: Single<(Entity, &mut Transform), With<Player>>,
+    mut commands: Commands,
+    level_return: Res<LevelReturn>,
+) {
+    let level = commands.spawn(level_bundle()).id();
+
+    let (entity, mut transform) = player.into_inner();
+    transform.translation.x = level_return.0.x;
+    transform.translation.y = level_return.0.y;
+    commands.entity(level).add_child(entity);
+    commands.remove_resource::<LevelReturn>();
+}
+
+/// Respawn the [`Player`] at a safe position near the [`RespawnPoint`] in the overworld.
+///
+/// The existing entity is reused so that references to it stay valid. If another level is active,
+/// the player is spawned there and returns to the [`RespawnPoint`] via [`LevelReturn`].
+/// Upgrades of [`Health::max`] are kept.
+pub(super) fn on_respawn_player(
+    _: On<RespawnPlayer>,
+    player: Single<(Entity, &Health), With<Player>>,
+    npc_query: Query<&Transform, With<Npc>>,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<ActiveLevel>>,
+    collision_data: Res<CollisionDataCache<Player>>,
+    level: Res<State<ActiveLevel>>,
+    rapier_context: ReadRapierContext,
+    respawn_point: Res<RespawnPoint>,
+) {
+    let (entity, health) = *player;
+    let max_health = Some(health.max);
+    // NOTE: Children are spawned again with the character.
+    commands
+        .entity(entity)
+        .despawn_related::<Children>()
+        .despawn_related::<StatusEffects>();
+
+    match level.get() {
+        ActiveLevel::Overworld => {
+            let rapier_context = rapier_context.single().expect(ERR_INVALID_RAPIER_CONTEXT);
+            let pos = safe_respawn_pos(
+                respawn_point.0,
+                entity,
+                &npc_query,
+                &rapier_context,
+                &collision_data,
+            );
+            commands.trigger(SpawnCharacter::<Player, Overworld> {
+                entity,
+                pos,
+                health: None,
+                max_health,
+                _phantom: PhantomData,
+            });
+        }
+        ActiveLevel::Interior => {
+            // NOTE: The overworld is not loaded, so the position can not be checked. The `RespawnPoint`
+            //       is where the player has entered a door, so it was safe at that time.
+            commands.trigger(SpawnCharacter::<Player, Interior> {
+                entity,
+                pos: respawn_point.0,
+                health: None,
+                max_health,
+                _phantom: PhantomData,
+            });
+            commands.insert_resource(LevelReturn(respawn_point.0));
+            (*next_state).set_if_neq(ActiveLevel::Overworld);
+        }
+    }
+}
+
+/// Nearest position around `point` that is clear of [`Npc`]s and [`Collider`]s.
+///
+/// Candidates are checked in square rings of [`RESPAWN_SEARCH_STEP`]. This falls back to `point`.
+fn safe_respawn_pos(
+    point: Vec2,
+    player: Entity,
+    npc_query: &Query<&Transform, With<Npc>>,
+    rapier_context: &RapierContext,
+    collision_data: &CollisionDataCache<Player>,
+) -> Vec2 {
+    let shape = shape::Cuboid::new(Vec2::new(collision_data.width, collision_data.height) / 2.);
+    // Filter for anything that is not the player
+    let filter = QueryFilter::default()
+        .exclude_sensors()
+        .exclude_rigid_body(player);
+
+    (0..=RESPAWN_SEARCH_RINGS)
+        .flat_map(|ring| {
+            (-ring..=ring)
+                .flat_map(move |y| (-ring..=ring).map(move |x| IVec2::new(x, y)))
+                .filter(move |offset| offset.abs().max_element() == ring)
+        })
+        .map(|offset| point + offset.as_vec2() * RESPAWN_SEARCH_STEP)
+        .find(|pos| {
+            let clear_of_npcs = npc_query
+                .iter()
+                .all(|t| t.translation.xy().distance(*pos) >= RESPAWN_NPC_DISTANCE);
+            let mut clear_of_colliders = true;
+            let shape_pos = *pos - Vec2::Y * collision_data.y_offset;
+            rapier_context.intersect_shape(shape_pos, 0., &shape, filter, |_| {
+                clear_of_colliders = false;
+                false
+            });
+            clear_of_npcs && clear_of_colliders
+        })
+        .unwrap_or(point)
+}
@@ -0,0 +1,105 @@
+//! Pickups that are collected by the player on contact.
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use serde::Deserialize;
+
+use crate::{characters::prelude::*, log::prelude::*, procgen::prelude::*, render::prelude::*};
+
+/// Size of a [`Pickup`] sprite in px.
+const PICKUP_SIZE: Vec2 = Vec2::new(6., 6.);
+
+/// Color of a [`PickupKind::Heal`] sprite.
+const HEAL_COLOR: Color = Color::srgb(0.85, 0.2, 0.25);
+
+/// Color of a [`PickupKind::MaxHealth`] sprite.
+const MAX_HEALTH_COLOR: Color = Color::srgb(0.95, 0.75, 0.2);
+
+/// [`Health`] that is restored by [`PickupKind::Heal`].
+pub(crate) const PICKUP_HEAL_AMOUNT: f32 = 3.;
+
+/// [`Health::max`] that is added by [`PickupKind::MaxHealth`].
+pub(crate) const PICKUP_MAX_HEALTH_AMOUNT: f32 = 2.;
+
+/// Chance that a procedurally generated [`Pickup`] is a [`PickupKind::MaxHealth`].
+pub(crate) const PICKUP_MAX_HEALTH_CHANCE: f64 = 0.1;
+
+/// Pickup marker.
+#[derive(Component, Default, Reflect, Clone)]
+pub(crate) struct Pickup;
+impl ProcGenerated for Pickup {}
+impl Visible for Pickup {}
+
+/// Effect of a [`Pickup`] on the [`Player`].
+///
+/// This is also dropped as [`Loot`] from a [`LootTable`].
+#[derive(Component, Deserialize, Clone, Copy, Debug)]
+pub(crate) enum PickupKind {
+    /// Restore [`Health::current`].
+    Heal(f32),
+    /// Permanently increase [`Health::max`].
+    MaxHealth(f32),
+}
+impl PickupKind {
+    /// Spawn a [`Pickup`] at `pos`.
+    pub(crate) fn spawn(self, commands: &mut Commands, pos: Vec2) -> Entity {
+        commands.spawn((Pickup, self.bundle(pos))).id()
+    }
+
+    /// Bundle of this at `pos` without the [`Pickup`] marker.
+    ///
+    /// This allows spawning it as [`Loot`], which is procedurally generated separately.
+    pub(crate) fn bundle(self, pos: Vec2) -> impl Bundle {
+        let (name, color) = match self {
+            Self::Heal(_) => ("Heal Pickup", HEAL_COLOR),
+            Self::MaxHealth(_) => ("Max Health Pickup", MAX_HEALTH_COLOR),
+        };
+
+        (
+            (Name::new(name), self),
+            // Positioning/Visibility
+            (
+                Sprite::from_color(color, PICKUP_SIZE),
+                // NOTE: Pickups lie on the ground, so they are rendered below y-sorted objects.
+                Transform::from_translation(pos.extend(FOREGROUND_Z - Y_SORT_OVERRIDE_Z_DELTA)),
+                Visibility::Inherited,
+            ),
+            // Physics
+            (
+                Collider::ball(PICKUP_SIZE.max_element() / 2.),
+                Sensor,
+                // NOTE: The player is kinematic, so this has to be enabled.
+                ActiveCollisionTypes::default() | ActiveCollisionTypes::KINEMATIC_STATIC,
+            ),
+        )
+    }
+}
+
+/// Apply [`PickupKind`] to the [`Player`] [`Health`] on contact and despawn the [`Pickup`].
+///
+/// This also collects [`PickupKind`]s that have been dropped as [`Loot`].
+pub(super) fn collect_pickups(
+    player: Single<(Entity, &mut Health), With<Player>>,
+    pickup_query: Query<(Entity, &PickupKind)>,
+    mut commands: Commands,
+    rapier_context: ReadRapierContext,
+) {
+    let rapier_context = rapier_context.single().expect(ERR_INVALID_RAPIER_CONTEXT);
+    let (entity, mut health) = player.into_inner();
+    if !health.is_alive() {
+        return;
+    }
+
+    for (pickup, kind) in &pickup_query {
+        if rapier_context.intersection_pair(entity, pickup) != Some(true) {
+            continue;
+        }
+
+        match kind {
+            PickupKind::Heal(amount) => health.heal(*amount),
+            PickupKind::MaxHealth(amount) => health.upgrade(*amount),
+        }
+        // NOTE: Using try here is necessary since the entity might have been despawned elsewhere.
+        commands.entity(pickup).try_despawn();
+    }
+}
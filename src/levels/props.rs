@@ -0,0 +1,153 @@
+//! Static level props that block movement and pathfinding.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use bevy_fast_light::prelude::*;
+use bevy_rapier2d::prelude::*;
+use serde::Deserialize;
+use vleue_navigator::prelude::*;
+
+use crate::{levels::prelude::*, procgen::prelude::*, render::prelude::*, utils::prelude::*};
+
+/// Size of a [`Door`] sprite in px.
+const DOOR_SIZE: Vec2 = Vec2::new(8., 4.);
+
+/// Prop marker.
+#[derive(Component, Default, Reflect, Clone)]
+pub(crate) struct Prop;
+impl ProcGenerated for Prop {}
+impl Visible for Prop {}
+
+/// Kind of [`Prop`] deserialized from a ron file.
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct PropKind {
+    pub(crate) name: String,
+    /// Path of the sprite image. Falls back to [`PropKind::color`] if this is [`None`].
+    #[serde(default)]
+    pub(crate) image: Option<String>,
+    /// Size of the sprite in px.
+    pub(crate) size: (f32, f32),
+    #[serde(default)]
+    pub(crate) color: Option<[u8; 3]>,
+    /// Collider shape. This can be `"ball"`, `"capsule"` or `"cuboid"`.
+    pub(crate) shape: String,
+    pub(crate) width: f32,
+    pub(crate) height: f32,
+    /// Whether this is spawned below every [`StreetLight`] instead of randomly.
+    #[serde(default)]
+    pub(crate) street_light: bool,
+    /// Whether this has a [`Door`] to [`ActiveLevel::Interior`] below its collider.
+    #[serde(default)]
+    pub(crate) door: bool,
+    #[serde(skip)]
+    pub(crate) image_handle: Option<Handle<Image>>,
+    #[serde(skip)]
+    pub(crate) shadow: Handle<Mesh>,
+}
+impl PropKind {
+    /// [`Collider`] matching [`PropKind::shape`].
+    fn collider(&self) -> Collider {
+        match self.shape.as_str() {
+            "ball" => Collider::ball(self.width / 2.),
+            "capsule" if self.height > self.width => {
+                Collider::capsule_y((self.height - self.width) / 2., self.width / 2.)
+            }
+            _ => Collider::cuboid(self.width / 2., self.height / 2.),
+        }
+    }
+
+    /// [`PrimitiveObstacle`] matching [`PropKind::collider`].
+    fn obstacle(&self) -> PrimitiveObstacle {
+        match self.shape.as_str() {
+            "ball" => PrimitiveObstacle::Circle(Circle::new(self.width / 2.)),
+            "capsule" if self.height > self.width => PrimitiveObstacle::Capsule(Capsule2d::new(
+                self.width / 2.,
+                self.height - self.width,
+            )),
+            _ => PrimitiveObstacle::Rectangle(Rectangle::new(self.width, self.height)),
+        }
+    }
+
+    /// Spawn a [`Prop`] with its collider centered at `pos`.
+    pub(crate) fn spawn(&self, commands: &mut Commands, pos: Vec2) -> Entity {
+        let size = Vec2::new(self.size.0, self.size.1);
+        let color = self.color.map_or(Color::WHITE, |c| color_from_rgb(&c));
+        let sprite = match &self.image_handle {
+            Some(image) => Sprite {
+                image: image.clone(),
+                custom_size: Some(size),
+                ..default()
+            },
+            None => Sprite::from_color(color, size),
+        };
+
+        let mut entity = commands.spawn((
+            (Name::new(self.name.clone()), Prop),
+            // Positioning/Visibility
+            (
+                Transform::from_translation(pos.extend(FOREGROUND_Z)),
+                YSort(FOREGROUND_Z),
+                // NOTE: Props are sorted by the bottom of their collider.
+                YSortYOffset(-self.height / 2.),
+                Visibility::Inherited,
+            ),
+            // Physics/Navigation
+            (RigidBody::Fixed, self.collider(), self.obstacle()),
+            children![
+                // NOTE: Align the bottom of the sprite with the bottom of the collider.
+                (
+                    sprite,
+                    Transform::from_xyz(0., (size.y - self.height) / 2., 0.),
+                ),
+                (
+                    MeshOccluder,
+                    Mesh2d(self.shadow.clone()),
+                    Transform::from_xyz(0., 0., -Y_SORT_OVERRIDE_Z_DELTA),
+                ),
+            ],
+        ));
+        if self.door {
+            entity.with_child((
+                Name::new("Door"),
+                Door(ActiveLevel::Interior),
+                Door::sprite(DOOR_SIZE),
+                Transform::from_xyz(0., -(self.height + DOOR_SIZE.y) / 2., 0.),
+            ));
+        }
+        entity.id()
+    }
+}
+
+/// Prop data deserialized from a ron file.
+#[derive(Deserialize, Asset, TypePath, Default)]
+pub(crate) struct PropData<T>
+where
+    T: ProcGenerated,
+{
+    #[serde(default)]
+    pub(crate) props: Vec<PropKind>,
+    #[serde(skip)]
+    pub(crate) _phantom: PhantomData<T>,
+}
+
+/// Handle for [`PropData`].
+#[derive(Resource)]
+pub(crate) struct PropHandle<T>(pub(crate) Handle<PropData<T>>)
+where
+    T: ProcGenerated;
+
+/// Cache for [`PropData`]
+///
+/// This is to allow easier access.
+#[derive(Resource, Default)]
+pub(crate) struct PropDataCache<T>
+where
+    T: ProcGenerated,
+{
+    /// Props that are placed randomly in chunks.
+    pub(crate) random: Vec<PropKind>,
+    /// Prop that is placed below every [`StreetLight`].
+    pub(crate) street_light: Option<PropKind>,
+    pub(crate) _phantom: PhantomData<T>,
+}
@@ -0,0 +1,86 @@
+//! Transitions between levels through doors.
+
+use bevy::prelude::*;
+
+use crate::{characters::prelude::*, levels::prelude::*};
+
+/// Distance in px to a [`Door`] below which the player enters it.
+const DOOR_RADIUS: f32 = 8.;
+
+/// Color of a [`Door`] sprite.
+const DOOR_COLOR: Color = Color::srgb(0.27, 0.16, 0.08);
+
+/// Door that leads to another [`ActiveLevel`].
+#[derive(Component, Clone, Copy, Debug)]
+pub(crate) struct Door(pub(crate) ActiveLevel);
+impl Door {
+    /// [`Sprite`] of a door with `size` in px.
+    pub(crate) fn sprite(size: Vec2) -> Sprite {
+        Sprite::from_color(DOOR_COLOR, size)
+    }
+}
+
+/// Whether the player is standing in a [`Door`].
+///
+/// Doors are only entered after the player has left all doors. This avoids entering a door right
+/// after arriving through it. This is `true` by default since the player might be spawned in a door.
+#[derive(Resource, Debug)]
+pub(crate) struct DoorOccupied(pub(crate) bool);
+impl Default for DoorOccupied {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+/// Position of the player in the overworld that is restored when returning to it.
+#[derive(Resource, Debug)]
+pub(crate) struct LevelReturn(pub(crate) Vec2);
+
+/// Position in the overworld that the player respawns at.
+///
+/// This is where the player has last entered a [`Door`] in the overworld or has been spawned initially.
+#[derive(Resource, Debug)]
+pub(crate) struct RespawnPoint(pub(crate) Vec2);
+
+/// Enter the [`ActiveLevel`] of a [`Door`] the player is standing in.
+pub(super) fn enter_doors(
+    player: Single<&GlobalTransform, With<Player>>,
+    door_query: Query<(&Door, &GlobalTransform)>,
+    mut next_state: ResMut<NextState<ActiveLevel>>,
+    mut occupied: ResMut<DoorOccupied>,
+) {
+    let pos = player.translation().xy();
+    let door = door_query
+        .iter()
+        .find(|(_, transform)| transform.translation().xy().distance(pos) < DOOR_RADIUS);
+
+    match door {
+        Some((door, _)) if !occupied.0 => {
+            occupied.0 = true;
+            (*next_state).set_if_neq(door.0);
+        }
+        Some(_) => {}
+        None => occupied.0 = false,
+    }
+}
+
+/// Store [`LevelReturn`] and [`RespawnPoint`] from the player position.
+pub(super) fn store_level_return(player: Single<&Transform, With<Player>>, mut commands: Commands) {
+    let pos = player.translation.xy();
+    commands.insert_resource(LevelReturn(pos));
+    commands.insert_resource(RespawnPoint(pos));
+}
+
+/// Despawn the [`Level`] of type `T` while keeping the player.
+///
+/// The player is added to the next level when it is spawned.
+pub(super) fn leave_level<T>(
+    level: Single<Entity, With<T>>,
+    player: Single<Entity, With<Player>>,
+    mut commands: Commands,
+) where
+    T: Level,
+{
+    commands.entity(*player).remove::<ChildOf>();
+    commands.entity(*level).despawn();
+}
@@ -25,6 +25,7 @@ mod mobile;
 mod physics;
 mod procgen;
 mod render;
+mod save;
 mod screens;
 mod ui;
 mod utils;
@@ -96,6 +97,7 @@ impl Plugin for AppPlugin {
             audio::AudioPlugin,
             levels::LevelsPlugin,
             render::RenderPlugin,
+            save::SavePlugin,
             images::ImagesPlugin,
             core::CorePlugin,
         ));
@@ -1,5 +1,3 @@
-/// Error on invalid attacker [`Entity`](bevy::prelude::Entity).
-pub(crate) const ERR_INVALID_ATTACKER: &str = "Invalid attacker entity. This might be a bug.";
 /// Error on invalid [`BoxShadow`](bevy::prelude::BoxShadow).
 pub(crate) const ERR_INVALID_BOX_SHADOW: &str = "Invalid box shadow. This might be a bug.";
 /// Error on invalid [`Children`](bevy::prelude::Children).
@@ -12,6 +10,9 @@ pub(crate) const ERR_INVALID_ENUM_PRIMITVE: &str =
     "Invalid primitive provided while turning into enum. This might be a bug.";
 /// Error on invalid [`Image`](bevy::prelude::Image).
 pub(crate) const ERR_INVALID_IMAGE: &str = "Invalid image. The config might be invalid.";
+/// Error on invalid [`ChunkPlacements`](crate::procgen::placements::ChunkPlacements).
+pub(crate) const ERR_INVALID_CHUNK_PLACEMENTS: &str =
+    "Invalid chunk placements. This might be a bug.";
 /// Error on invalid [`ProcGenCache::chunk_positions`](crate::procgen::ProcGenCache::chunk_positions).
 pub(crate) const ERR_INVALID_CHUNK_POSITIONS: &str =
     "Invalid chunk positions. This might be a bug.";
@@ -25,15 +26,45 @@ pub(crate) const ERR_INVALID_RAPIER_CONTEXT: &str = "Invalid rapier context. Thi
 /// Error on loading [`AnimationData`](crate::animations::prelude::AnimationData).
 pub(crate) const ERR_LOADING_ANIMATION_DATA: &str =
     "Could not load animation data. The config might be missing.";
+/// Error on loading [`ArchetypeData`](crate::characters::prelude::ArchetypeData).
+pub(crate) const ERR_LOADING_ARCHETYPE_DATA: &str =
+    "Could not load archetype data. The config might be missing.";
+/// Error on loading [`AttackData`](crate::characters::prelude::AttackData).
+pub(crate) const ERR_LOADING_ATTACK_DATA: &str =
+    "Could not load attack data. The config might be missing.";
+/// Error on loading [`BiomeData`](crate::procgen::prelude::BiomeData).
+pub(crate) const ERR_LOADING_BIOME_DATA: &str =
+    "Could not load biome data. The config might be missing.";
 /// Error on loading [`CollisionData`](crate::physics::prelude::CollisionData).
 pub(crate) const ERR_LOADING_COLLISION_DATA: &str =
     "Could not load collision data. The config might be missing.";
 /// Error on loading [`CreditsData`](crate::ui::prelude::CreditsData).
 pub(crate) const ERR_LOADING_CREDITS_DATA: &str =
     "Could not load credits data. The config might be missing.";
+/// Error on loading [`ItemData`](crate::characters::prelude::ItemData).
+pub(crate) const ERR_LOADING_ITEM_DATA: &str =
+    "Could not load item data. The config might be missing.";
 /// Error on loading [`LayerData`](crate::images::prelude::LayerData).
 pub(crate) const ERR_LOADING_LAYER_DATA: &str =
     "Could not load layer data. The config might be missing.";
+/// Error on loading [`LootData`](crate::characters::prelude::LootData).
+pub(crate) const ERR_LOADING_LOOT_DATA: &str =
+    "Could not load loot data. The config might be missing.";
+/// Error on loading [`MapData`](crate::levels::prelude::MapData).
+pub(crate) const ERR_LOADING_MAP_DATA: &str =
+    "Could not load map data. The config might be missing.";
+/// Error on loading [`ProcGenData`](crate::procgen::prelude::ProcGenData).
+pub(crate) const ERR_LOADING_PROCGEN_DATA: &str =
+    "Could not load procgen data. The config might be missing.";
+/// Error on loading [`ProgressionData`](crate::characters::prelude::ProgressionData).
+pub(crate) const ERR_LOADING_PROGRESSION_DATA: &str =
+    "Could not load progression data. The config might be missing.";
+/// Error on loading [`PropData`](crate::levels::prelude::PropData).
+pub(crate) const ERR_LOADING_PROP_DATA: &str =
+    "Could not load prop data. The config might be missing.";
+/// Error on loading [`StampData`](crate::procgen::prelude::StampData).
+pub(crate) const ERR_LOADING_STAMP_DATA: &str =
+    "Could not load stamp data. The config might be missing.";
 /// Error on loading [`TileData`](crate::images::prelude::TileData).
 pub(crate) const ERR_LOADING_TILE_DATA: &str =
     "Could not load tile data. The config might be missing.";
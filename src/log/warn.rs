@@ -4,11 +4,41 @@
 pub(crate) const WARN_INCOMPLETE_ANIMATION_DATA: &str = "Incomplete animation data.";
 /// Warning on incomplete asset data.
 pub(crate) const WARN_INCOMPLETE_ASSET_DATA: &str = "Incomplete asset data.";
+/// Warning on incomplete [`BiomeData`](crate::procgen::prelude::BiomeData).
+pub(crate) const WARN_INCOMPLETE_BIOME_DATA: &str =
+    "Incomplete biome data. Falling back to a default biome.";
 /// Warning on incomplete [`CollisionData`](crate::physics::prelude::CollisionData).
 pub(crate) const WARN_INCOMPLETE_COLLISION_DATA: &str = "Incomplete collision data.";
+/// Warning on incomplete [`ProcGenData`](crate::procgen::prelude::ProcGenData).
+pub(crate) const WARN_INCOMPLETE_PROCGEN_DATA: &str = "Incomplete procgen data.";
+/// Warning on incomplete [`TileData`](crate::images::prelude::TileData).
+pub(crate) const WARN_INCOMPLETE_TILE_DATA: &str = "Incomplete tile data.";
+
+/// Warning on failing to write [`SaveData`](crate::save::SaveData).
+pub(crate) const WARN_FAILED_SAVE: &str = "Failed to write save file.";
+
+/// Warning on incompatible version of [`SaveData`](crate::save::SaveData).
+pub(crate) const WARN_INCOMPATIBLE_SAVE_VERSION: &str =
+    "Incompatible save file version. The save file will be ignored.";
 
 /// Warning on invalid [`AttackData`](crate::characters::prelude::AttackData`).
 pub(crate) const WARN_INVALID_ATTACK_DATA: &str = "Invalid attack data.";
+/// Warning on invalid [`MapLayout`](crate::levels::prelude::MapLayout).
+pub(crate) const WARN_INVALID_MAP_DATA: &str =
+    "Invalid map data. Tiles referencing missing tile sets are left empty.";
+/// Warning on invalid [`SaveData`](crate::save::SaveData).
+pub(crate) const WARN_INVALID_SAVE: &str = "Invalid save file. The save file will be ignored.";
+/// Warning on unknown [`Archetype`](crate::characters::prelude::Archetype).
+pub(crate) const WARN_UNKNOWN_ARCHETYPE: &str =
+    "Unknown archetype. No characters of it will be spawned.";
+/// Warning on unknown [`Item`](crate::characters::prelude::Item) of [`LootKind::Item`](crate::characters::prelude::LootKind::Item).
+pub(crate) const WARN_UNKNOWN_LOOT_ITEM: &str = "Unknown loot item. The item will be ignored.";
+/// Warning on unknown [`Item`](crate::characters::prelude::Item) in [`SaveData`](crate::save::SaveData).
+pub(crate) const WARN_UNKNOWN_SAVED_ITEM: &str =
+    "Unknown item in save file. The item will be ignored.";
 /// Warning on invalid [`UiNavAction`](crate::input::prelude::UiNavAction`).
 pub(crate) const WARN_INVALID_UI_NAV: &str =
     "Invalid ui nav action. No next button found to navigate to.";
+/// Warning on invalid [`WorldSeed`](crate::procgen::prelude::WorldSeed).
+pub(crate) const WARN_INVALID_WORLD_SEED: &str =
+    "Invalid world seed. Falling back to a random world seed.";
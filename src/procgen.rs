@@ -12,16 +12,30 @@
 //        will be just fine. That is a world of 4096x4096 chunks with 16 16x16 tiles.
 //        Also see: https://github.com/bevyengine/bevy/issues/1680
 
+mod biomes;
 mod characters;
 mod chunks;
 mod common;
+mod config;
+mod deltas;
 mod lights;
+mod loot;
 mod mist;
+mod pickups;
+mod placements;
+mod props;
+mod seed;
+mod stamps;
+mod terrain;
 
 pub(crate) mod prelude {
+    pub(crate) use super::biomes::{Biome, BiomeData, BiomeDataCache, BiomeHandle, CurrentBiome};
+    pub(crate) use super::config::{ProcGenData, ProcGenDataCache, ProcGenHandle};
+    pub(crate) use super::deltas::{ProcGenDelta, ProcGenDeltas, ProcGenOrigin};
+    pub(crate) use super::seed::{WorldSeed, seed_rng};
+    pub(crate) use super::stamps::{MapStamp, StampData, StampDataCache, StampHandle};
     pub(crate) use super::{
-        CHUNK_SIZE, DespawnProcGen, PROCGEN_DISTANCE, ProcGen, ProcGenCache, ProcGenInit,
-        ProcGenRng, ProcGenState, ProcGenerated,
+        DespawnProcGen, ProcGen, ProcGenCache, ProcGenInit, ProcGenState, ProcGenerated,
     };
 }
 
@@ -35,7 +49,7 @@ use bevy::{
 
 use crate::{
     characters::prelude::*, core::prelude::*, levels::prelude::*, log::prelude::*,
-    render::prelude::*, screens::prelude::*, utils::prelude::*,
+    render::prelude::*, screens::prelude::*,
 };
 
 pub(super) struct ProcGenPlugin;
@@ -47,16 +61,8 @@ impl Plugin for ProcGenPlugin {
 
         app.add_systems(
             OnEnter(ProcGenState::Spawn),
-            (
-                chunks::spawn_chunks::<OverworldProcGen, Overworld>,
-                (
-                    common::spawn_objects::<Slime, OverworldProcGen, Overworld>,
-                    common::spawn_objects::<StreetLight, OverworldProcGen, Overworld>,
-                    common::spawn_objects::<StandardMist, OverworldProcGen, Overworld>,
-                ),
-            )
-                .run_if(in_state(Screen::Gameplay))
-                .chain(),
+            chunks::spawn_chunks::<OverworldProcGen, Overworld>
+                .run_if(in_state(ActiveLevel::Overworld)),
         );
         app.add_systems(
             OnExit(Screen::Gameplay),
@@ -64,61 +70,157 @@ impl Plugin for ProcGenPlugin {
                 reset_procgen_state,
                 reset_procgen_init,
                 reset_procgen_despawning,
+                // NOTE: This replaces the `WorldSeed` of a loaded save so that new games do not reuse it.
+                seed::insert_world_seed,
             ),
         );
-        app.add_systems(Startup, setup_rng::<ProcGenRng>);
+        // NOTE: Loaded objects are recorded and caches are cleared before the overworld is despawned.
+        //       Otherwise all loaded objects would be recorded as removed.
+        app.add_systems(
+            OnExit(ActiveLevel::Overworld),
+            (
+                (
+                    deltas::record_loaded_changes::<Slime>,
+                    deltas::record_loaded_changes::<Villager>,
+                    deltas::record_loaded_changes::<StreetLight>,
+                    deltas::record_loaded_changes::<StandardMist>,
+                    deltas::record_loaded_changes::<Pickup>,
+                    deltas::record_loaded_changes::<Loot>,
+                ),
+                (
+                    clear_procgen_cache::<OverworldProcGen>,
+                    clear_procgen_cache::<Slime>,
+                    clear_procgen_cache::<Villager>,
+                    clear_procgen_cache::<StreetLight>,
+                    clear_procgen_cache::<StandardMist>,
+                    clear_procgen_cache::<Pickup>,
+                    clear_procgen_cache::<Prop>,
+                    clear_procgen_cache::<Loot>,
+                    biomes::reset_current_biome::<OverworldProcGen>,
+                ),
+                (
+                    reset_procgen_state,
+                    reset_procgen_init,
+                    reset_procgen_despawning,
+                ),
+            )
+                .chain()
+                .run_if(in_state(Screen::Gameplay)),
+        );
+        app.add_systems(Startup, seed::insert_world_seed);
         app.add_systems(
             Update,
             (
                 common::collect_to_despawn::<OverworldProcGen, OverworldProcGen, true>,
                 common::collect_to_despawn::<Slime, OverworldProcGen, false>,
+                common::collect_to_despawn::<Villager, OverworldProcGen, false>,
                 common::collect_to_despawn::<StreetLight, OverworldProcGen, false>,
                 common::collect_to_despawn::<StandardMist, OverworldProcGen, false>,
+                common::collect_to_despawn::<Pickup, OverworldProcGen, false>,
+                common::collect_to_despawn::<Prop, OverworldProcGen, false>,
+                common::collect_to_despawn::<Loot, OverworldProcGen, false>,
             )
-                .run_if(in_state(ProcGenState::Despawn).and(in_state(Screen::Gameplay)))
+                .run_if(in_state(ProcGenState::Despawn).and(in_state(ActiveLevel::Overworld)))
                 .in_set(AppSystems::Update)
                 .in_set(PausableSystems),
         );
+        app.add_systems(
+            Update,
+            (
+                chunks::apply_chunk_tasks::<OverworldProcGen, OverworldAssets>,
+                (
+                    common::spawn_objects::<Prop, OverworldProcGen>,
+                    common::spawn_objects::<Slime, OverworldProcGen>,
+                    common::spawn_objects::<Villager, OverworldProcGen>,
+                    common::spawn_objects::<StreetLight, OverworldProcGen>,
+                    common::spawn_objects::<StandardMist, OverworldProcGen>,
+                    common::spawn_objects::<Pickup, OverworldProcGen>,
+                    common::spawn_objects::<Loot, OverworldProcGen>,
+                ),
+                chunks::finish_spawning.run_if(chunks::chunk_tasks_finished::<OverworldProcGen>),
+            )
+                .chain()
+                .run_if(in_state(ProcGenState::Spawn).and(in_state(ActiveLevel::Overworld)))
+                .in_set(AppSystems::Update),
+        );
+        app.add_systems(
+            Update,
+            (
+                deltas::record_spawned::<Slime, ArchetypeIndex, OverworldProcGen, Overworld>,
+                deltas::record_spawned::<Loot, LootDropIndex, OverworldProcGen, Overworld>,
+            )
+                .run_if(in_state(ActiveLevel::Overworld))
+                .in_set(AppSystems::Update),
+        );
+        // NOTE: Boss music replaces biome music during a boss fight.
+        app.add_systems(
+            Update,
+            (
+                biomes::reset_current_biome::<OverworldProcGen>
+                    .run_if(resource_removed::<BossFight>),
+                biomes::update_biome_music::<OverworldProcGen, OverworldAssets, Overworld>
+                    .run_if(not(resource_exists::<BossFight>)),
+            )
+                .chain()
+                .run_if(in_state(ActiveLevel::Overworld))
+                .in_set(AppSystems::Update),
+        );
         app.add_systems(
             PostUpdate,
             (
                 (
                     common::set_despawning::<OverworldProcGen>,
                     common::set_despawning::<Slime>,
+                    common::set_despawning::<Villager>,
                     common::set_despawning::<StreetLight>,
                     common::set_despawning::<StandardMist>,
+                    common::set_despawning::<Pickup>,
+                    common::set_despawning::<Prop>,
+                    common::set_despawning::<Loot>,
                 ),
                 (
+                    deltas::record_changes::<Slime>,
+                    deltas::record_changes::<Villager>,
+                    deltas::record_changes::<StreetLight>,
+                    deltas::record_changes::<StandardMist>,
+                    deltas::record_changes::<Pickup>,
+                    deltas::record_changes::<Loot>,
                     common::despawn::<Slime>,
+                    common::despawn::<Villager>,
                     common::despawn::<StreetLight>,
                     common::despawn::<StandardMist>,
+                    common::despawn::<Pickup>,
+                    common::despawn::<Prop>,
+                    common::despawn::<Loot>,
                     common::despawn::<OverworldProcGen>,
                 )
                     .run_if(in_state(DespawnProcGen(true)))
                     .chain(),
             )
-                .run_if(in_state(Screen::Gameplay)),
+                .run_if(in_state(ActiveLevel::Overworld)),
         );
 
         app.add_observer(
             chunks::spawn_on_procgen_chunks::<OverworldProcGen, OverworldAssets, Overworld>,
         );
-        app.add_observer(
-            characters::spawn_on_procgen_characters::<Slime, OverworldProcGen, Overworld>,
-        );
+        app.add_observer(characters::spawn_on_procgen_characters::<Slime, Overworld>);
+        app.add_observer(characters::spawn_on_procgen_villagers::<Villager, Overworld>);
         app.add_observer(
             lights::spawn_on_procgen_lights::<StreetLight, OverworldProcGen, Overworld>,
         );
         app.add_observer(mist::spawn_on_procgen_mist::<StandardMist, OverworldProcGen, Overworld>);
+        app.add_observer(props::spawn_on_procgen_props::<OverworldProcGen, Overworld>);
+        app.add_observer(pickups::spawn_on_procgen_pickups::<Overworld>);
+        app.add_observer(loot::spawn_on_procgen_loot::<Slime, Overworld>);
+        app.add_observer(deltas::on_remove_record_delta::<Slime>);
+        app.add_observer(deltas::on_remove_record_delta::<Villager>);
+        app.add_observer(deltas::on_remove_record_delta::<StreetLight>);
+        app.add_observer(deltas::on_remove_record_delta::<StandardMist>);
+        app.add_observer(deltas::on_remove_record_delta::<Pickup>);
+        app.add_observer(deltas::on_remove_record_delta::<Loot>);
     }
 }
 
-/// Size of a single chunk
-pub(crate) const CHUNK_SIZE: UVec2 = UVec2 { x: 16, y: 16 };
-
-/// Maximum distance for procedural generation
-pub(crate) const PROCGEN_DISTANCE: i32 = 2;
-
 /// Tracks the current proc gen task
 #[derive(States, Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
 pub(crate) enum ProcGenState {
@@ -141,6 +243,10 @@ pub(crate) trait ProcGenerated
 where
     Self: Component + Default + Reflectable,
 {
+    /// Kind of [`MapObject`] that places this in stamped chunks instead of random placement.
+    fn map_object() -> Option<MapObjectKind> {
+        None
+    }
 }
 
 #[derive(EntityEvent)]
@@ -180,10 +286,14 @@ where
     }
 }
 
-/// Rng for procedural generation
-#[derive(Component, Default)]
-pub(crate) struct ProcGenRng;
-impl ForkedRng for ProcGenRng {}
+/// Clear [`ProcGenCache<T>`] without recording removed entities.
+fn clear_procgen_cache<T>(mut cache: ResMut<ProcGenCache<T>>)
+where
+    T: ProcGenerated,
+{
+    cache.chunk_positions.clear();
+    cache.to_despawn.clear();
+}
 
 /// Reset [`ProcGenState`]
 fn reset_procgen_state(mut next_state: ResMut<NextState<ProcGenState>>) {
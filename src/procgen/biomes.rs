@@ -0,0 +1,189 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use bevy_prng::WyRand;
+use rand::seq::IndexedRandom as _;
+use serde::Deserialize;
+
+use crate::{
+    audio::prelude::*, levels::prelude::*, log::prelude::*, procgen::prelude::*, utils::prelude::*,
+};
+
+/// Number of octaves of biome noise.
+const BIOME_NOISE_OCTAVES: u32 = 2;
+
+/// Biome deserialized from a ron file.
+///
+/// Biomes are chosen by comparing biome noise to [`Biome::max_noise`] in ascending order.
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct Biome {
+    pub(crate) name: String,
+    pub(crate) max_noise: f32,
+    pub(crate) grass_threshold: f32,
+    #[serde(default)]
+    pub(crate) tile_color: Option<[u8; 3]>,
+    pub(crate) characters_per_chunk: usize,
+    /// Number of villagers near street lights in chunks with enough of them.
+    pub(crate) villagers_per_chunk: usize,
+    pub(crate) lights_per_chunk: usize,
+    pub(crate) mist_per_chunk: usize,
+    pub(crate) props_per_chunk: usize,
+    pub(crate) pickups_per_chunk: usize,
+    #[serde(default)]
+    pub(crate) mist_color: Option<([u8; 3], u8)>,
+    /// Indexes into [`LevelAssets::music`].
+    #[serde(default)]
+    pub(crate) music: Option<Vec<usize>>,
+}
+impl Default for Biome {
+    fn default() -> Self {
+        Self {
+            name: "Default".to_string(),
+            max_noise: 1.,
+            grass_threshold: 0.45,
+            tile_color: None,
+            characters_per_chunk: 1,
+            villagers_per_chunk: 0,
+            lights_per_chunk: 4,
+            mist_per_chunk: 1,
+            props_per_chunk: 2,
+            pickups_per_chunk: 0,
+            mist_color: None,
+            music: None,
+        }
+    }
+}
+
+/// Biome data deserialized from a ron file.
+#[derive(Deserialize, Asset, TypePath, Default)]
+pub(crate) struct BiomeData<T>
+where
+    T: ProcGenerated,
+{
+    /// Frequency of biome noise per chunk.
+    pub(crate) frequency: f32,
+    #[serde(default)]
+    pub(crate) biomes: Vec<Biome>,
+    #[serde(skip)]
+    pub(crate) _phantom: PhantomData<T>,
+}
+
+/// Handle for [`BiomeData`].
+#[derive(Resource)]
+pub(crate) struct BiomeHandle<T>(pub(crate) Handle<BiomeData<T>>)
+where
+    T: ProcGenerated;
+
+/// Cache for [`BiomeData`]
+///
+/// This is to allow easier access.
+#[derive(Resource, Default, Clone)]
+pub(crate) struct BiomeDataCache<T>
+where
+    T: ProcGenerated,
+{
+    pub(crate) frequency: f32,
+    /// Biomes sorted by [`Biome::max_noise`].
+    ///
+    /// This is guaranteed to contain at least one [`Biome`].
+    pub(crate) biomes: Vec<Biome>,
+    pub(crate) _phantom: PhantomData<T>,
+}
+impl<T> BiomeDataCache<T>
+where
+    T: ProcGenerated,
+{
+    /// Index of the [`Biome`] for the chunk at `chunk_pos`.
+    pub(crate) fn biome_index(&self, seed: &WorldSeed, chunk_pos: IVec2) -> usize {
+        // NOTE: We are mixing in a constant to avoid correlation with terrain noise.
+        let noise = fbm_2d(
+            seed.seed::<T>() ^ hash_str("biome"),
+            chunk_pos.as_vec2() * self.frequency,
+            BIOME_NOISE_OCTAVES,
+        );
+        self.biomes
+            .iter()
+            .position(|b| noise <= b.max_noise)
+            .unwrap_or(self.biomes.len() - 1)
+    }
+
+    /// [`Biome`] for the chunk at `chunk_pos`.
+    pub(crate) fn biome(&self, seed: &WorldSeed, chunk_pos: IVec2) -> &Biome {
+        &self.biomes[self.biome_index(seed, chunk_pos)]
+    }
+}
+
+/// Index of the [`Biome`] the camera is currently in.
+#[derive(Resource, Default)]
+pub(crate) struct CurrentBiome<T>
+where
+    T: ProcGenerated,
+{
+    pub(crate) index: Option<usize>,
+    _phantom: PhantomData<T>,
+}
+
+/// Reset [`CurrentBiome`] so that music is chosen again.
+pub(super) fn reset_current_biome<T>(mut current: ResMut<CurrentBiome<T>>)
+where
+    T: ProcGenerated,
+{
+    current.index = None;
+}
+
+/// Marker for music that is chosen by [`Biome::music`].
+#[derive(Component)]
+pub(super) struct BiomeMusic;
+
+/// Switch music if the camera enters a different [`Biome`].
+///
+/// Music keeps playing if it is also contained in [`Biome::music`] of the new [`Biome`].
+pub(super) fn update_biome_music<T, A, B>(
+    level: Single<Entity, With<B>>,
+    music_query: Query<(Entity, &AudioPlayer), With<BiomeMusic>>,
+    mut level_rng: Single<&mut WyRand, With<LevelRng>>,
+    mut commands: Commands,
+    mut current: ResMut<CurrentBiome<T>>,
+    biomes: Res<BiomeDataCache<T>>,
+    cache: Res<ProcGenCache<T>>,
+    level_assets: Res<A>,
+    seed: Res<WorldSeed>,
+) where
+    T: ProcGenerated,
+    A: LevelAssets,
+    B: Level,
+{
+    let index = biomes.biome_index(&seed, cache.camera_chunk_pos);
+    if current.index == Some(index) {
+        return;
+    }
+    current.index = Some(index);
+
+    // Choose from level music that is allowed in biome or all level music
+    let level_music = level_assets.music().clone().unwrap_or_else(|| {
+        warn_once!("{}", WARN_INCOMPLETE_ASSET_DATA);
+        Vec::default()
+    });
+    let biome_music: Vec<_> = match &biomes.biomes[index].music {
+        Some(indexes) => indexes
+            .iter()
+            .filter_map(|i| level_music.get(*i).cloned())
+            .collect(),
+        None => level_music,
+    };
+    if music_query
+        .iter()
+        .any(|(_, player)| biome_music.contains(&player.0))
+    {
+        return;
+    }
+
+    for (entity, _) in music_query {
+        commands.entity(entity).despawn();
+    }
+    if let Some(handle) = biome_music.choose(&mut level_rng).cloned() {
+        commands.entity(*level).with_children(|commands| {
+            commands.spawn((Name::new("Gameplay Music"), BiomeMusic, music(handle)));
+        });
+    }
+}
@@ -1,44 +1,207 @@
-use std::marker::PhantomData;
+use std::{f32::consts::TAU, marker::PhantomData, ops::Range};
 
 use bevy::prelude::*;
-use bevy_prng::WyRand;
-use rand::seq::IndexedRandom as _;
+use rand::{RngExt as _, seq::IndexedRandom as _};
 
-use crate::{characters::prelude::*, images::prelude::*, levels::prelude::*, procgen::prelude::*};
+use crate::{
+    characters::prelude::*,
+    images::prelude::*,
+    levels::prelude::*,
+    log::prelude::*,
+    procgen::{
+        placements::{ChunkPlacements, Placement, PlacementContext},
+        prelude::*,
+    },
+    render::prelude::*,
+};
 
-/// Number of characters to spawn per chunk
-const CHARACTERS_PER_CHUNK: usize = 1;
+/// [`Placement`]s of characters `T` in a chunk.
+///
+/// Each character gets a random archetype if there are `archetypes` for `T`.
+/// Stamped chunks additionally place the boss archetype at [`MapObjectKind::Boss`] spawn points.
+pub(super) fn place_characters<T, A>(
+    context: &PlacementContext<A>,
+    archetypes: Option<&ArchetypeDataCache<T>>,
+) -> Vec<Placement>
+where
+    T: Character + ProcGenerated + Visible,
+    A: ProcGenerated,
+{
+    let mut rng = context.rng::<T>();
+    let target_origins = context.origins::<T>(&mut rng, context.biome.characters_per_chunk);
 
-/// Spawn characters in a chunk.
-pub(super) fn spawn_on_procgen_characters<T, A, B>(
+    // NOTE: Bosses come after other characters so that their indexes are stable.
+    let boss = archetypes.and_then(ArchetypeDataCache::boss);
+    let boss_origins = boss
+        .and_then(|_| context.kind_origins(MapObjectKind::Boss))
+        .unwrap_or_default();
+    target_origins
+        .into_iter()
+        .map(|origin| (origin, false))
+        .chain(boss_origins.into_iter().map(|origin| (origin, true)))
+        .enumerate()
+        .map(|(index, (origin, is_boss))| {
+            // NOTE: The archetype is chosen for every placement to keep `rng` in sync across generations.
+            let choice = if is_boss {
+                boss
+            } else {
+                archetypes.and_then(|a| a.choose(&mut rng))
+            };
+            Placement {
+                index,
+                pos: context.world_pos(origin),
+                choice,
+            }
+        })
+        .collect()
+}
+
+/// Spawn characters from [`ChunkPlacements`] of a chunk.
+///
+/// [`Placement::choice`] is used as [`ArchetypeIndex`].
+/// Characters that have been spawned outside of procgen in this chunk are spawned again from [`ProcGenDeltas`].
+pub(super) fn spawn_on_procgen_characters<T, B>(
     event: On<ProcGen<T>>,
-    mut procgen_rng: Single<&mut WyRand, With<ProcGenRng>>,
+    placement_query: Query<&ChunkPlacements>,
     mut commands: Commands,
     mut object_cache: ResMut<ProcGenCache<T>>,
-    tile_data: Res<TileDataCache<A>>,
+    deltas: Res<ProcGenDeltas<T>>,
 ) where
-    T: Character + ProcGenerated,
+    T: Character + ProcGenerated + Visible,
+    B: Level,
+{
+    let placements = placement_query
+        .get(event.entity)
+        .expect(ERR_INVALID_CHUNK_PLACEMENTS);
+
+    for placement in placements.get::<T>() {
+        // Apply delta from previous generations of this chunk
+        let (pos, health) = match deltas.get(event.chunk_pos, placement.index) {
+            Some(ProcGenDelta::Removed) => continue,
+            Some(ProcGenDelta::Changed { pos, health }) => (*pos, *health),
+            Some(ProcGenDelta::Spawned { .. }) | None => (placement.pos, None),
+        };
+
+        // Spawn entity at placement and store in `object_cache`
+        let origin = ProcGenOrigin {
+            chunk_pos: event.chunk_pos,
+            index: placement.index,
+            pos: placement.pos,
+        };
+        let mut entity = commands.spawn((T::default(), origin));
+        if let Some(archetype) = placement.choice {
+            entity.insert(ArchetypeIndex(archetype));
+        }
+        let entity = entity.id();
+        commands.trigger(SpawnCharacter::<T, B> {
+            entity,
+            pos,
+            health,
+            max_health: None,
+            _phantom: PhantomData,
+        });
+        object_cache.chunk_positions.insert(entity, event.chunk_pos);
+    }
+
+    // Spawn characters that have been spawned outside of procgen, e.g. by splitting
+    for (index, pos, health, archetype) in deltas.spawned(event.chunk_pos) {
+        let origin = ProcGenOrigin {
+            chunk_pos: event.chunk_pos,
+            index,
+            pos,
+        };
+        let mut entity = commands.spawn((T::default(), origin));
+        if let Some(archetype) = archetype {
+            entity.insert(ArchetypeIndex(archetype));
+        }
+        let entity = entity.id();
+        commands.trigger(SpawnCharacter::<T, B> {
+            entity,
+            pos,
+            health,
+            max_health: None,
+            _phantom: PhantomData,
+        });
+        object_cache.chunk_positions.insert(entity, event.chunk_pos);
+    }
+}
+
+/// Minimum number of lights in a chunk to place villagers near them.
+const VILLAGER_MIN_LIGHTS: usize = 2;
+
+/// [`Range`] of distance in tiles from a light that villagers are placed at.
+const VILLAGER_LIGHT_DISTANCE_RANGE_TILES: Range<f32> = 1.0..3.0;
+
+/// [`Placement`]s of villagers `T` near `lights` in a chunk.
+///
+/// Each villager gets a random [`DisplayVariant`] out of `variants` to vary their appearance.
+pub(super) fn place_villagers<T, A>(
+    context: &PlacementContext<A>,
+    lights: &[Placement],
+    variants: usize,
+) -> Vec<Placement>
+where
+    T: Character + ProcGenerated + Visible,
     A: ProcGenerated,
+{
+    if lights.len() < VILLAGER_MIN_LIGHTS || variants == 0 {
+        return Vec::new();
+    }
+    let mut rng = context.rng::<T>();
+
+    (0..context.biome.villagers_per_chunk)
+        .filter_map(|index| {
+            let light = lights.choose(&mut rng)?;
+            let offset = Vec2::from_angle(rng.random_range(0.0..TAU))
+                * rng.random_range(VILLAGER_LIGHT_DISTANCE_RANGE_TILES)
+                * context.tile_size;
+            Some(Placement {
+                index,
+                pos: light.pos + offset,
+                choice: Some(rng.random_range(0..variants)),
+            })
+        })
+        .collect()
+}
+
+/// Spawn villagers from [`ChunkPlacements`] of a chunk.
+///
+/// [`Placement::choice`] is used as [`DisplayVariant`].
+pub(super) fn spawn_on_procgen_villagers<T, B>(
+    event: On<ProcGen<T>>,
+    placement_query: Query<&ChunkPlacements>,
+    mut commands: Commands,
+    mut object_cache: ResMut<ProcGenCache<T>>,
+    deltas: Res<ProcGenDeltas<T>>,
+) where
+    T: Character + ProcGenerated + Visible,
     B: Level,
 {
-    let world_pos = event.chunk_pos.as_vec2() * CHUNK_SIZE.as_vec2() * tile_data.tile_size;
-
-    // Choose a number of target chunk tile origins to determine spawn positions
-    let target_origins: Vec<(u32, u32)> = (0..CHUNK_SIZE.x)
-        .flat_map(|x| (0..CHUNK_SIZE.y).map(move |y| (x, y)))
-        .collect();
-    let target_origins: Vec<Vec2> = target_origins
-        .sample(&mut procgen_rng, CHARACTERS_PER_CHUNK)
-        .map(|&(x, y)| Vec2::new(x as f32, y as f32))
-        .collect();
-
-    for origin in target_origins {
-        // Spawn entity in chosen tile and store in `object_cache`
-        let entity = commands.spawn(T::default()).id();
-        let pos = world_pos + origin * tile_data.tile_size;
+    let placements = placement_query
+        .get(event.entity)
+        .expect(ERR_INVALID_CHUNK_PLACEMENTS);
+
+    for placement in placements.get::<T>() {
+        // Apply delta from previous generations of this chunk
+        let (pos, health) = match deltas.get(event.chunk_pos, placement.index) {
+            Some(ProcGenDelta::Removed) => continue,
+            Some(ProcGenDelta::Changed { pos, health }) => (*pos, *health),
+            Some(ProcGenDelta::Spawned { .. }) | None => (placement.pos, None),
+        };
+
+        // Spawn entity at placement and store in `object_cache`
+        let origin = ProcGenOrigin {
+            chunk_pos: event.chunk_pos,
+            index: placement.index,
+            pos: placement.pos,
+        };
+        let variant = DisplayVariant(placement.choice.unwrap_or_default());
+        let entity = commands.spawn((T::default(), origin, variant)).id();
         commands.trigger(SpawnCharacter::<T, B> {
             entity,
             pos,
+            health,
+            max_health: None,
             _phantom: PhantomData,
         });
         object_cache.chunk_positions.insert(entity, event.chunk_pos);
@@ -1,18 +1,34 @@
 use std::marker::PhantomData;
 
-use bevy::prelude::*;
+use bevy::{
+    prelude::*,
+    tasks::{AsyncComputeTaskPool, Task, block_on, futures_lite::future},
+};
 use bevy_ecs_tilemap::prelude::*;
-use bevy_prng::WyRand;
-use rand::RngExt as _;
 
-use crate::{images::prelude::*, levels::prelude::*, procgen::prelude::*, render::prelude::*};
+use crate::{
+    characters::prelude::*,
+    images::prelude::*,
+    levels::prelude::*,
+    log::prelude::*,
+    procgen::{
+        characters, lights, mist, pickups,
+        placements::{ChunkPlacements, PlacementContext},
+        prelude::*,
+        props, terrain,
+    },
+    render::prelude::*,
+    utils::prelude::*,
+};
 
 /// Spawn chunks around the camera.
+///
+/// Chunk content is applied by [`apply_chunk_tasks`] over the following frames.
 pub(super) fn spawn_chunks<T, A>(
     level: Single<Entity, With<A>>,
     mut commands: Commands,
-    mut next_state: ResMut<NextState<ProcGenState>>,
     cache: Res<ProcGenCache<T>>,
+    config: Res<ProcGenDataCache<T>>,
 ) where
     T: ProcGenerated,
     A: Level,
@@ -23,8 +39,9 @@ pub(super) fn spawn_chunks<T, A>(
     //       I deem this a cleaner solution and if spawning in a circle, distance calculations
     //       would be more expensive.
     let chunk_pos = cache.camera_chunk_pos;
-    for y in (chunk_pos.y - PROCGEN_DISTANCE)..=(chunk_pos.y + PROCGEN_DISTANCE) {
-        for x in (chunk_pos.x - PROCGEN_DISTANCE)..=(chunk_pos.x + PROCGEN_DISTANCE) {
+    let distance = config.procgen_distance as i32;
+    for y in (chunk_pos.y - distance)..=(chunk_pos.y + distance) {
+        for x in (chunk_pos.x - distance)..=(chunk_pos.x + distance) {
             // Continue if a chunk has already been stored
             if cache
                 .chunk_positions
@@ -40,68 +57,235 @@ pub(super) fn spawn_chunks<T, A>(
             });
         }
     }
+}
 
-    (*next_state).set_if_neq(ProcGenState::MoveNavMesh);
+/// Maximum number of finished [`ChunkTask`]s that are applied per frame.
+const CHUNK_TASKS_PER_FRAME: usize = 2;
+
+/// Content of a chunk computed by a [`ChunkTask`].
+pub(super) struct ChunkContent {
+    chunk_pos: IVec2,
+    /// Texture indexes of tiles ordered by rows.
+    texture_indexes: Vec<u32>,
+    color: TileColor,
+    /// Number of columns in the tile set.
+    columns: u32,
+    /// [`Map`] stamped into this chunk.
+    stamp: Option<Map>,
+    placements: ChunkPlacements,
 }
 
-/// Spawn a single chunk.
+/// Task computing [`ChunkContent`] on the [`AsyncComputeTaskPool`].
+#[derive(Component)]
+pub(super) struct ChunkTask(Task<ChunkContent>);
+
+/// Spawn a single chunk container and start a [`ChunkTask`] for its content.
+///
+/// This also computes [`ChunkPlacements`] of all procedurally generated objects in the chunk.
 pub(super) fn spawn_on_procgen_chunks<T, A, B>(
     event: On<ProcGen<T>>,
     level: Single<Entity, With<B>>,
-    mut rng: Single<&mut WyRand, With<ProcGenRng>>,
     mut commands: Commands,
     mut cache: ResMut<ProcGenCache<T>>,
     assets: Res<A>,
+    images: Res<Assets<Image>>,
+    biomes: Res<BiomeDataCache<T>>,
+    config: Res<ProcGenDataCache<T>>,
+    props: Res<PropDataCache<T>>,
+    seed: Res<WorldSeed>,
+    stamps: Res<StampDataCache<T>>,
     tile_data: Res<TileDataCache<T>>,
+    variants: Res<DisplayVariants<Villager>>,
+    archetypes: Option<Res<ArchetypeDataCache<Slime>>>,
 ) where
-    T: ProcGenerated,
+    T: ProcGenerated + Clone,
     A: LevelAssets,
     B: Level,
 {
+    // Determine number of columns in tile set
+    let image = assets.tile_set().clone();
+    let columns = images.get(&image).expect(ERR_INVALID_IMAGE).width() / tile_data.tile_size as u32;
+
+    // Compute tiles from the chunk seed without blocking the main thread
+    // NOTE: Tiles are chosen from their global position to make chunks stitch seamlessly.
+    //       Tiles of the first layer of a stamped map replace generated tiles.
+    let chunk_pos = event.chunk_pos;
+    let stamp = stamps.maps.get(&chunk_pos).cloned();
+    let (seed, biomes, config, tile_data) =
+        (*seed, biomes.clone(), config.clone(), tile_data.clone());
+    let (props, variants) = (props.random.len(), variants.len());
+    let archetypes = archetypes.map(|a| ArchetypeDataCache::<Slime> {
+        archetypes: a.archetypes.clone(),
+        _phantom: PhantomData,
+    });
+    let task = AsyncComputeTaskPool::get().spawn(async move {
+        let chunk_origin = chunk_pos * config.chunk_size.as_ivec2();
+        let texture_indexes = (0..config.chunk_size.y)
+            .flat_map(|y| (0..config.chunk_size.x).map(move |x| IVec2::new(x as i32, y as i32)))
+            .map(|offset| {
+                let coords = stamp
+                    .as_ref()
+                    .and_then(|map| map.tile(0, offset.as_uvec2()))
+                    .or_else(|| {
+                        terrain::tile_coords(
+                            &seed,
+                            chunk_origin + offset,
+                            &tile_data,
+                            &biomes,
+                            &config,
+                        )
+                    });
+                terrain::tile_texture_index(coords, columns)
+            })
+            .collect();
+        let biome = biomes.biome(&seed, chunk_pos).clone();
+        let color = biome
+            .tile_color
+            .map_or(TileColor::default(), |c| TileColor(color_from_rgb(&c)));
+
+        // Compute placements of objects
+        // NOTE: Villagers are placed near lights, so lights have to be placed first.
+        let context = PlacementContext {
+            chunk_pos,
+            seed,
+            biome,
+            tile_size: tile_data.tile_size,
+            config,
+            stamp,
+        };
+        let mut placements = ChunkPlacements::default();
+        let lights = lights::place_lights::<StreetLight, T>(&context);
+        placements.insert::<Villager>(characters::place_villagers::<Villager, T>(
+            &context, &lights, variants,
+        ));
+        placements.insert::<StreetLight>(lights);
+        placements.insert::<Slime>(characters::place_characters(&context, archetypes.as_ref()));
+        placements.insert::<StandardMist>(mist::place_mist::<StandardMist, T>(&context));
+        placements.insert::<Pickup>(pickups::place_pickups(&context));
+        placements.insert::<Prop>(props::place_props(&context, props));
+
+        ChunkContent {
+            chunk_pos,
+            texture_indexes,
+            color,
+            columns,
+            stamp: context.stamp,
+            placements,
+        }
+    });
+
     // Create empty container and store in `cache`
-    let container = commands.spawn(T::default()).id();
-    cache.chunk_positions.insert(container, event.chunk_pos);
-    let mut storage = TileStorage::empty(CHUNK_SIZE.into());
-
-    // Spawn a `TileBundle` mapped to the container entity for each x/y in `CHUNK_SIZE`,
-    // add as child to container entity and add to storage.
-    for y in 0..CHUNK_SIZE.y {
-        for x in 0..CHUNK_SIZE.x {
-            // FIXME: Currently this just chooses from a range of random numbers.
-            //        Make this choose from tiles in a way that makes sense with noise.
-            let texture_index = TileTextureIndex(rng.random_range(0..8));
-            let tile_pos = TilePos { x, y };
+    // NOTE: The container is stored right away to avoid spawning the chunk again while computing.
+    let container = commands.spawn((T::default(), ChunkTask(task))).id();
+    cache.chunk_positions.insert(container, chunk_pos);
+
+    // Add chunk container to level so that level handles despawning
+    commands.entity(*level).add_child(container);
+}
+
+/// Apply finished [`ChunkTask`]s to their chunk containers.
+///
+/// At most [`CHUNK_TASKS_PER_FRAME`] are applied per frame to spread the cost of spawning tiles and objects.
+/// Objects are spawned from the inserted [`ChunkPlacements`] by [`spawn_objects`](crate::procgen::common::spawn_objects).
+pub(super) fn apply_chunk_tasks<T, A>(
+    mut task_query: Query<(Entity, &mut ChunkTask), With<T>>,
+    mut commands: Commands,
+    assets: Res<A>,
+    config: Res<ProcGenDataCache<T>>,
+    tile_data: Res<TileDataCache<T>>,
+) where
+    T: ProcGenerated,
+    A: LevelAssets,
+{
+    let chunk_size = config.chunk_size;
+    let finished = task_query
+        .iter_mut()
+        .filter_map(|(container, mut task)| {
+            block_on(future::poll_once(&mut task.0)).map(|content| (container, content))
+        })
+        .take(CHUNK_TASKS_PER_FRAME);
+
+    for (container, content) in finished {
+        // Spawn a `TileBundle` mapped to the container entity for each x/y in `chunk_size`,
+        // add as child to container entity and add to storage.
+        let mut storage = TileStorage::empty(chunk_size.into());
+        for (i, texture_index) in content.texture_indexes.into_iter().enumerate() {
+            let tile_pos = TilePos {
+                x: i as u32 % chunk_size.x,
+                y: i as u32 / chunk_size.x,
+            };
             let entity = commands
                 .spawn(TileBundle {
                     position: tile_pos,
-                    texture_index,
+                    texture_index: TileTextureIndex(texture_index),
                     tilemap_id: TilemapId(container),
+                    color: content.color,
                     ..default()
                 })
                 .id();
             commands.entity(container).add_child(entity);
             storage.set(&tile_pos, entity);
         }
+
+        // Spawn further layers and doors of stamped map
+        if let Some(map) = &content.stamp {
+            for layer in 1..map.layers.len() {
+                let entity = commands.spawn(Name::new("Stamp Layer")).id();
+                map.spawn_layer(
+                    &mut commands,
+                    entity,
+                    layer,
+                    assets.tile_set().clone(),
+                    content.columns,
+                    Transform::from_xyz(0., 0., LAYER_Z_DELTA * layer as f32),
+                );
+                commands.entity(container).add_child(entity);
+            }
+            for pos in map.positions(MapObjectKind::Door) {
+                commands.entity(container).with_child((
+                    Name::new("Door"),
+                    Door(ActiveLevel::Interior),
+                    Door::sprite(Vec2::splat(map.tile_size)),
+                    Transform::from_translation(
+                        map.world_pos(pos)
+                            .extend(LAYER_Z_DELTA * map.layers.len() as f32),
+                    ),
+                ));
+            }
+        }
+
+        // Insert TileMapBundle with storage, transform and texture from handle to container entity
+        let tile_size_vec = Vec2::splat(tile_data.tile_size);
+        let world_pos = content.chunk_pos.as_vec2() * chunk_size.as_vec2() * tile_size_vec;
+        commands
+            .entity(container)
+            .insert(TilemapBundle {
+                grid_size: tile_size_vec.into(),
+                size: chunk_size.into(),
+                storage,
+                texture: TilemapTexture::Single(assets.tile_set().clone()),
+                tile_size: tile_size_vec.into(),
+                transform: Transform::from_translation(world_pos.extend(LEVEL_Z)),
+                render_settings: TilemapRenderSettings {
+                    render_chunk_size: chunk_size,
+                    y_sort: false,
+                },
+                ..default()
+            })
+            .insert(content.placements)
+            .remove::<ChunkTask>();
     }
+}
 
-    // Insert TileMapBundle with storage, transform and texture from handle to container entity
-    let tile_size_vec = Vec2::splat(tile_data.tile_size);
-    let world_pos = event.chunk_pos.as_vec2() * CHUNK_SIZE.as_vec2() * tile_size_vec;
-    let image = assets.tile_set().clone();
-    commands.entity(container).insert(TilemapBundle {
-        grid_size: tile_size_vec.into(),
-        size: CHUNK_SIZE.into(),
-        storage,
-        texture: TilemapTexture::Single(image),
-        tile_size: tile_size_vec.into(),
-        transform: Transform::from_translation(world_pos.extend(LEVEL_Z)),
-        render_settings: TilemapRenderSettings {
-            render_chunk_size: CHUNK_SIZE,
-            y_sort: false,
-        },
-        ..default()
-    });
+/// Whether all [`ChunkTask`]s have been applied.
+pub(super) fn chunk_tasks_finished<T>(task_query: Query<(), (With<ChunkTask>, With<T>)>) -> bool
+where
+    T: ProcGenerated,
+{
+    task_query.is_empty()
+}
 
-    // Add chunk container to level so that level handles despawning
-    commands.entity(*level).add_child(container);
+/// Proceed to [`ProcGenState::MoveNavMesh`].
+pub(super) fn finish_spawning(mut next_state: ResMut<NextState<ProcGenState>>) {
+    (*next_state).set_if_neq(ProcGenState::MoveNavMesh);
 }
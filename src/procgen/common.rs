@@ -2,38 +2,38 @@ use std::marker::PhantomData;
 
 use bevy::prelude::*;
 
-use crate::{levels::prelude::*, procgen::prelude::*, render::prelude::*};
+use crate::{
+    characters::prelude::*,
+    levels::prelude::*,
+    procgen::{placements::ChunkPlacements, prelude::*},
+    render::prelude::*,
+};
 
-/// Spawn objects in every chunk contained in [`ProcGenCache<A>`]
-pub(super) fn spawn_objects<T, A, B>(
-    level: Single<Entity, With<B>>,
+/// Spawn objects `T` in every chunk `A` that [`ChunkPlacements`] have been added to.
+pub(super) fn spawn_objects<T, A>(
+    chunk_query: Query<Entity, (With<A>, Added<ChunkPlacements>)>,
     mut commands: Commands,
     chunk_cache: Res<ProcGenCache<A>>,
-    object_cache: Res<ProcGenCache<T>>,
 ) where
     T: ProcGenerated,
     A: ProcGenerated,
-    B: Level,
 {
-    for (_, chunk_pos) in &chunk_cache.chunk_positions {
-        // Continue if chunk has already been stored
-        if object_cache
-            .chunk_positions
-            .values()
-            .any(|&v| v == *chunk_pos)
-        {
+    for container in &chunk_query {
+        let Some(chunk_pos) = chunk_cache.chunk_positions.get(&container) else {
             continue;
-        }
+        };
 
         commands.trigger(ProcGen::<T> {
-            entity: *level,
+            entity: container,
             chunk_pos: *chunk_pos,
             _phantom: PhantomData,
         });
     }
 }
 
-/// Collect procedurally generated [`Entity`]s to despawn outside of [`PROCGEN_DISTANCE`].
+/// Collect procedurally generated [`Entity`]s to despawn outside of [`ProcGenDataCache::procgen_distance`].
+///
+/// Entities in the arena of a [`BossFight`] are kept.
 ///
 /// `const PROCEED` determines whether we should proceed to the next state.
 pub(super) fn collect_to_despawn<T, A, const PROCEED: bool>(
@@ -41,7 +41,9 @@ pub(super) fn collect_to_despawn<T, A, const PROCEED: bool>(
     query: Query<(Entity, &Transform), (With<T>, Without<CanvasCamera>)>,
     mut cache: ResMut<ProcGenCache<T>>,
     mut next_state: ResMut<NextState<ProcGenState>>,
+    config: Res<ProcGenDataCache<A>>,
     level_dimensions: Res<LevelDimensions<A>>,
+    fight: Option<Res<BossFight>>,
 ) where
     T: ProcGenerated,
     A: ProcGenerated,
@@ -49,14 +51,18 @@ pub(super) fn collect_to_despawn<T, A, const PROCEED: bool>(
     let chunk_size_px = level_dimensions.chunk_size_px;
     cache.camera_chunk_pos = (camera.translation.xy() / chunk_size_px).floor().as_ivec2();
 
-    // Add entities outside of `PROCGEN_DISTANCE` to `to_despawn`
+    // Add entities outside of `procgen_distance` to `to_despawn`
     for (entity, transform) in query {
         let chunk_pos = (transform.translation.xy() / chunk_size_px)
             .floor()
             .as_ivec2();
 
         // NOTE: We are using `chebyshev_distance` because we are spawning in a square.
-        if cache.camera_chunk_pos.chebyshev_distance(chunk_pos) > PROCGEN_DISTANCE as u32 {
+        if cache.camera_chunk_pos.chebyshev_distance(chunk_pos) > config.procgen_distance
+            && fight
+                .as_ref()
+                .is_none_or(|f| !f.contains_chunk(chunk_pos, chunk_size_px))
+        {
             cache.to_despawn.insert(entity);
         }
     }
@@ -0,0 +1,81 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::procgen::prelude::*;
+
+/// Default size of a single chunk in tiles.
+const DEFAULT_CHUNK_SIZE: UVec2 = UVec2 { x: 16, y: 16 };
+
+/// Default maximum distance for procedural generation in chunks.
+const DEFAULT_PROCGEN_DISTANCE: u32 = 2;
+
+/// Procedural generation config deserialized from a ron file.
+#[derive(Deserialize, Asset, TypePath, Default)]
+pub(crate) struct ProcGenData<T>
+where
+    T: ProcGenerated,
+{
+    #[serde(default)]
+    pub(crate) chunk_size: Option<(u32, u32)>,
+    #[serde(default)]
+    pub(crate) procgen_distance: Option<u32>,
+    #[serde(skip)]
+    pub(crate) _phantom: PhantomData<T>,
+}
+
+/// Handle for [`ProcGenData`].
+#[derive(Resource)]
+pub(crate) struct ProcGenHandle<T>(pub(crate) Handle<ProcGenData<T>>)
+where
+    T: ProcGenerated;
+
+/// Cache for [`ProcGenData`]
+///
+/// This is to allow easier access.
+#[derive(Resource, Clone)]
+pub(crate) struct ProcGenDataCache<T>
+where
+    T: ProcGenerated,
+{
+    /// Size of a single chunk in tiles.
+    pub(crate) chunk_size: UVec2,
+    /// Maximum distance for procedural generation in chunks.
+    pub(crate) procgen_distance: u32,
+    pub(crate) _phantom: PhantomData<T>,
+}
+impl<T> Default for ProcGenDataCache<T>
+where
+    T: ProcGenerated,
+{
+    fn default() -> Self {
+        Self {
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            procgen_distance: DEFAULT_PROCGEN_DISTANCE,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<T> ProcGenDataCache<T>
+where
+    T: ProcGenerated,
+{
+    /// Number of horizontal/vertical chunks in a straight line.
+    pub(crate) fn num_chunks(&self) -> u32 {
+        self.procgen_distance * 2 + 1
+    }
+
+    /// Chunk position containing the tile at global tile position `pos`.
+    pub(crate) fn chunk_pos(&self, pos: IVec2) -> IVec2 {
+        pos.div_euclid(self.chunk_size.as_ivec2())
+    }
+
+    /// Tile origins within a chunk.
+    pub(crate) fn tile_origins(&self) -> Vec<(u32, u32)> {
+        let chunk_size = self.chunk_size;
+        (0..chunk_size.x)
+            .flat_map(|x| (0..chunk_size.y).map(move |y| (x, y)))
+            .collect()
+    }
+}
@@ -0,0 +1,285 @@
+use std::{marker::PhantomData, ops::Deref};
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::{characters::prelude::*, levels::prelude::*, procgen::prelude::*};
+
+/// Distance in pixels below which an object is not considered to have moved.
+const DELTA_POS_TOLERANCE: f32 = 0.5;
+
+/// Offset of [`ProcGenOrigin::index`] for objects that have been spawned outside of procgen.
+///
+/// This keeps their indexes apart from those of generated objects.
+const SPAWNED_INDEX_OFFSET: usize = 1 << 16;
+
+/// Origin of a procedurally generated object.
+///
+/// `index` is the position of the object in the order it was generated in for `chunk_pos`.
+/// Since chunk contents only depend on [`WorldSeed`], this identifies the object across despawns.
+///
+/// Objects that have been spawned outside of procgen, e.g. by splitting or as loot, get an index starting at [`SPAWNED_INDEX_OFFSET`].
+#[derive(Component, Clone, Copy, Debug)]
+pub(crate) struct ProcGenOrigin {
+    pub(crate) chunk_pos: IVec2,
+    pub(crate) index: usize,
+    pub(crate) pos: Vec2,
+}
+impl ProcGenOrigin {
+    /// Whether the object has been spawned outside of procgen.
+    pub(crate) fn is_spawned(&self) -> bool {
+        self.index >= SPAWNED_INDEX_OFFSET
+    }
+}
+
+/// Change of a procedurally generated object compared to its generated state.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ProcGenDelta {
+    Removed,
+    Changed {
+        pos: Vec2,
+        health: Option<f32>,
+    },
+    /// Object that has been spawned outside of procgen and is spawned again with its chunk.
+    Spawned {
+        pos: Vec2,
+        health: Option<f32>,
+        /// Index of a choice that the object has been spawned with, e.g. an archetype or a loot drop.
+        choice: Option<usize>,
+    },
+}
+
+/// Store of [`ProcGenDelta`]s per chunk position and [`ProcGenOrigin::index`].
+///
+/// This is consulted when a chunk is generated again, so that changes persist.
+#[derive(Resource, Default, Debug)]
+pub(crate) struct ProcGenDeltas<T>
+where
+    T: ProcGenerated,
+{
+    pub(crate) chunks: HashMap<IVec2, HashMap<usize, ProcGenDelta>>,
+    _phantom: PhantomData<T>,
+}
+impl<T> ProcGenDeltas<T>
+where
+    T: ProcGenerated,
+{
+    /// [`ProcGenDeltas`] from entries of chunk position, index and [`ProcGenDelta`].
+    pub(crate) fn from_entries(
+        entries: impl IntoIterator<Item = (IVec2, usize, ProcGenDelta)>,
+    ) -> Self {
+        let mut deltas = Self::default();
+        for (chunk_pos, index, delta) in entries {
+            deltas
+                .chunks
+                .entry(chunk_pos)
+                .or_default()
+                .insert(index, delta);
+        }
+        deltas
+    }
+
+    /// Entries of chunk position, index and [`ProcGenDelta`].
+    pub(crate) fn entries(&self) -> Vec<(IVec2, usize, ProcGenDelta)> {
+        self.chunks
+            .iter()
+            .flat_map(|(chunk_pos, c)| c.iter().map(|(index, delta)| (*chunk_pos, *index, *delta)))
+            .collect()
+    }
+
+    /// [`ProcGenDelta`] of the object at `index` in the chunk at `chunk_pos`.
+    pub(crate) fn get(&self, chunk_pos: IVec2, index: usize) -> Option<&ProcGenDelta> {
+        self.chunks.get(&chunk_pos).and_then(|c| c.get(&index))
+    }
+
+    /// [`ProcGenDelta::Spawned`] entries with their index in the chunk at `chunk_pos`.
+    pub(crate) fn spawned(
+        &self,
+        chunk_pos: IVec2,
+    ) -> impl Iterator<Item = (usize, Vec2, Option<f32>, Option<usize>)> {
+        self.chunks
+            .get(&chunk_pos)
+            .into_iter()
+            .flatten()
+            .filter_map(|(index, delta)| match delta {
+                ProcGenDelta::Spawned {
+                    pos,
+                    health,
+                    choice,
+                } => Some((*index, *pos, *health, *choice)),
+                _ => None,
+            })
+    }
+
+    /// Record an object that has been spawned outside of procgen at `pos` in the chunk at `chunk_pos`.
+    ///
+    /// Returns the [`ProcGenOrigin`] of the object.
+    pub(crate) fn record_spawned(
+        &mut self,
+        chunk_pos: IVec2,
+        pos: Vec2,
+        choice: Option<usize>,
+    ) -> ProcGenOrigin {
+        let index = self
+            .chunks
+            .get(&chunk_pos)
+            .and_then(|c| c.keys().filter(|i| **i >= SPAWNED_INDEX_OFFSET).max())
+            .map_or(SPAWNED_INDEX_OFFSET, |i| i + 1);
+        let origin = ProcGenOrigin {
+            chunk_pos,
+            index,
+            pos,
+        };
+        self.insert(
+            &origin,
+            ProcGenDelta::Spawned {
+                pos,
+                health: None,
+                choice,
+            },
+        );
+        origin
+    }
+
+    /// Record the current state of the object at `origin`.
+    ///
+    /// Objects that are unchanged compared to their [`ProcGenOrigin`] have their delta removed.
+    /// Spawned objects always keep their delta so that they are spawned again.
+    pub(crate) fn record(&mut self, origin: &ProcGenOrigin, pos: Vec2, health: Option<&Health>) {
+        let health_value = health.map(|h| h.current);
+        if origin.is_spawned() {
+            if let Some(ProcGenDelta::Spawned {
+                pos: spawned_pos,
+                health: spawned_health,
+                ..
+            }) = self
+                .chunks
+                .get_mut(&origin.chunk_pos)
+                .and_then(|c| c.get_mut(&origin.index))
+            {
+                *spawned_pos = pos;
+                *spawned_health = health_value;
+            }
+            return;
+        }
+
+        let moved = !pos.abs_diff_eq(origin.pos, DELTA_POS_TOLERANCE);
+        let damaged = health.is_some_and(|h| h.current < h.max);
+        if moved || damaged {
+            self.insert(
+                origin,
+                ProcGenDelta::Changed {
+                    pos,
+                    health: health_value,
+                },
+            );
+        } else {
+            self.remove(origin);
+        }
+    }
+
+    /// Insert `delta` for `origin`.
+    fn insert(&mut self, origin: &ProcGenOrigin, delta: ProcGenDelta) {
+        self.chunks
+            .entry(origin.chunk_pos)
+            .or_default()
+            .insert(origin.index, delta);
+    }
+
+    /// Remove the [`ProcGenDelta`] for `origin`.
+    fn remove(&mut self, origin: &ProcGenOrigin) {
+        if let Some(chunk) = self.chunks.get_mut(&origin.chunk_pos) {
+            chunk.remove(&origin.index);
+            if chunk.is_empty() {
+                self.chunks.remove(&origin.chunk_pos);
+            }
+        }
+    }
+}
+
+/// Record [`ProcGenDelta`]s for entities in [`ProcGenCache<T>::to_despawn`].
+pub(super) fn record_changes<T>(
+    query: Query<(&Transform, &ProcGenOrigin, Option<&Health>), With<T>>,
+    mut deltas: ResMut<ProcGenDeltas<T>>,
+    cache: Res<ProcGenCache<T>>,
+) where
+    T: ProcGenerated,
+{
+    for entity in &cache.to_despawn {
+        if let Ok((transform, origin, health)) = query.get(*entity) {
+            deltas.record(origin, transform.translation.xy(), health);
+        }
+    }
+}
+
+/// Record [`ProcGenDelta`]s for all loaded entities.
+pub(super) fn record_loaded_changes<T>(
+    query: Query<(&Transform, &ProcGenOrigin, Option<&Health>), With<T>>,
+    mut deltas: ResMut<ProcGenDeltas<T>>,
+) where
+    T: ProcGenerated,
+{
+    for (transform, origin, health) in query {
+        deltas.record(origin, transform.translation.xy(), health);
+    }
+}
+
+/// Record [`ProcGenDelta::Removed`] for entities that are removed outside of procgen despawning.
+///
+/// Procgen despawning removes entities from [`ProcGenCache<T>::chunk_positions`] before despawning them,
+/// so any entity that is still contained in there has been removed elsewhere, e.g. killed.
+pub(super) fn on_remove_record_delta<T>(
+    event: On<Remove, T>,
+    query: Query<&ProcGenOrigin>,
+    cache: Option<ResMut<ProcGenCache<T>>>,
+    deltas: Option<ResMut<ProcGenDeltas<T>>>,
+) where
+    T: ProcGenerated,
+{
+    // NOTE: The resources might have been removed already when exiting the gameplay screen.
+    let (Some(mut cache), Some(mut deltas)) = (cache, deltas) else {
+        return;
+    };
+    let Ok(origin) = query.get(event.entity) else {
+        return;
+    };
+
+    // NOTE: Spawned objects are not generated again, so their delta can be removed entirely.
+    if cache.chunk_positions.remove(&event.entity).is_some() {
+        if origin.is_spawned() {
+            deltas.remove(origin);
+        } else {
+            deltas.insert(origin, ProcGenDelta::Removed);
+        }
+    }
+}
+
+/// Record `T` that have been spawned in level `B` outside of procgen, e.g. by splitting.
+///
+/// This inserts a [`ProcGenOrigin`] and stores them in [`ProcGenCache<T>`], so that they are despawned
+/// and spawned again with their chunk like generated objects. `C` is stored as [`ProcGenDelta::Spawned::choice`].
+pub(super) fn record_spawned<T, C, A, B>(
+    query: Query<(Entity, &Transform, &ChildOf, Option<&C>), (With<T>, Without<ProcGenOrigin>)>,
+    level: Single<Entity, With<B>>,
+    mut commands: Commands,
+    mut cache: ResMut<ProcGenCache<T>>,
+    mut deltas: ResMut<ProcGenDeltas<T>>,
+    level_dimensions: Res<LevelDimensions<A>>,
+) where
+    T: ProcGenerated,
+    C: Component + Deref<Target = usize>,
+    A: ProcGenerated,
+    B: Level,
+{
+    for (entity, transform, child_of, choice) in &query {
+        if child_of.parent() != *level {
+            continue;
+        }
+
+        let pos = transform.translation.xy();
+        let chunk_pos = (pos / level_dimensions.chunk_size_px).floor().as_ivec2();
+        let origin = deltas.record_spawned(chunk_pos, pos, choice.map(|c| **c));
+        commands.entity(entity).insert(origin);
+        cache.chunk_positions.insert(entity, chunk_pos);
+    }
+}
@@ -1,44 +1,80 @@
 use bevy::prelude::*;
-use bevy_prng::WyRand;
-use rand::seq::IndexedRandom as _;
 
-use crate::{images::prelude::*, levels::prelude::*, procgen::prelude::*, render::prelude::*};
+use crate::{
+    levels::prelude::*,
+    log::prelude::*,
+    procgen::{
+        placements::{ChunkPlacements, Placement, PlacementContext},
+        prelude::*,
+    },
+    render::prelude::*,
+};
 
-/// Number of lights to spawn per chunk.
-const LIGHTS_PER_CHUNK: usize = 4;
+/// [`Placement`]s of lights `T` in a chunk.
+pub(super) fn place_lights<T, A>(context: &PlacementContext<A>) -> Vec<Placement>
+where
+    T: LightWrapper + ProcGenerated + Visible,
+    A: ProcGenerated,
+{
+    let mut rng = context.rng::<T>();
+    context
+        .origins::<T>(&mut rng, context.biome.lights_per_chunk)
+        .into_iter()
+        .enumerate()
+        .map(|(index, origin)| Placement {
+            index,
+            pos: context.world_pos(origin),
+            choice: None,
+        })
+        .collect()
+}
 
-/// Spawn lights in a chunk.
+/// Spawn lights from [`ChunkPlacements`] of a chunk.
 pub(super) fn spawn_on_procgen_lights<T, A, B>(
     event: On<ProcGen<T>>,
     level: Single<Entity, With<B>>,
-    mut procgen_rng: Single<&mut WyRand, With<ProcGenRng>>,
+    placement_query: Query<&ChunkPlacements>,
     mut commands: Commands,
     mut object_cache: ResMut<ProcGenCache<T>>,
+    mut prop_cache: ResMut<ProcGenCache<Prop>>,
+    deltas: Res<ProcGenDeltas<T>>,
     mesh: Res<LightMeshHandle<T>>,
-    tile_data: Res<TileDataCache<A>>,
+    props: Res<PropDataCache<A>>,
 ) where
     T: LightWrapper + ProcGenerated + Visible,
     A: ProcGenerated,
     B: Level,
 {
-    let world_pos = event.chunk_pos.as_vec2() * CHUNK_SIZE.as_vec2() * tile_data.tile_size;
+    let placements = placement_query
+        .get(event.entity)
+        .expect(ERR_INVALID_CHUNK_PLACEMENTS);
 
-    // Choose a number of target chunk tile origins to determine spawn positions
-    let target_origins: Vec<(u32, u32)> = (0..CHUNK_SIZE.x)
-        .flat_map(|x| (0..CHUNK_SIZE.y).map(move |y| (x, y)))
-        .collect();
-    let target_origins: Vec<Vec2> = target_origins
-        .sample(&mut procgen_rng, LIGHTS_PER_CHUNK)
-        .map(|&(x, y)| Vec2::new(x as f32, y as f32))
-        .collect();
+    for placement in placements.get::<T>() {
+        // Apply delta from previous generations of this chunk
+        let pos = match deltas.get(event.chunk_pos, placement.index) {
+            Some(ProcGenDelta::Removed) => continue,
+            Some(ProcGenDelta::Changed { pos, .. }) => *pos,
+            Some(ProcGenDelta::Spawned { .. }) | None => placement.pos,
+        };
 
-    for origin in target_origins {
-        // Spawn entity in chosen tile and store in `object_cache`
-        let target_pos = world_pos + origin * tile_data.tile_size;
-        let entity = T::new(mesh.handle.clone()).spawn(&mut commands, target_pos);
+        // Spawn entity at placement and store in `object_cache`
+        let entity = T::new(mesh.handle.clone()).spawn(&mut commands, pos);
+        commands.entity(entity).insert(ProcGenOrigin {
+            chunk_pos: event.chunk_pos,
+            index: placement.index,
+            pos: placement.pos,
+        });
         object_cache.chunk_positions.insert(entity, event.chunk_pos);
 
         // Add entity to level so that level handles despawning
         commands.entity(*level).add_child(entity);
+
+        // Spawn prop below light
+        // NOTE: This is stored in `prop_cache` so that it is despawned with the chunk.
+        if let Some(prop) = &props.street_light {
+            let entity = prop.spawn(&mut commands, pos);
+            prop_cache.chunk_positions.insert(entity, event.chunk_pos);
+            commands.entity(*level).add_child(entity);
+        }
     }
 }
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+
+use crate::{characters::prelude::*, levels::prelude::*, procgen::prelude::*};
+
+/// Spawn [`Loot`] that has been dropped by `T` in a chunk from [`ProcGenDeltas`].
+///
+/// [`ProcGenDelta::Spawned::choice`] is used as [`LootDropIndex`] into [`LootDataCache<T>`].
+pub(super) fn spawn_on_procgen_loot<T, B>(
+    event: On<ProcGen<Loot>>,
+    level: Single<Entity, With<B>>,
+    mut commands: Commands,
+    mut object_cache: ResMut<ProcGenCache<Loot>>,
+    deltas: Res<ProcGenDeltas<Loot>>,
+    loot: Option<Res<LootDataCache<T>>>,
+) where
+    T: Visible,
+    B: Level,
+{
+    let Some(loot) = loot else {
+        return;
+    };
+
+    for (index, pos, _, choice) in deltas.spawned(event.chunk_pos) {
+        let Some((choice, drop)) = choice.and_then(|i| loot.drops.get(i).map(|d| (i, d))) else {
+            continue;
+        };
+
+        // Spawn entity at its dropped position and store in `object_cache`
+        // NOTE: The bob phase only needs to differ between neighbouring loot.
+        let entity = drop.kind.spawn(&mut commands, pos, index as f32);
+        commands.entity(entity).insert((
+            LootDropIndex(choice),
+            ProcGenOrigin {
+                chunk_pos: event.chunk_pos,
+                index,
+                pos,
+            },
+        ));
+        object_cache.chunk_positions.insert(entity, event.chunk_pos);
+
+        // Add entity to level so that level handles despawning
+        commands.entity(*level).add_child(entity);
+    }
+}
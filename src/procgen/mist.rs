@@ -1,41 +1,75 @@
 use bevy::prelude::*;
-use bevy_prng::WyRand;
-use rand::seq::IndexedRandom as _;
 
-use crate::{images::prelude::*, levels::prelude::*, procgen::prelude::*, render::prelude::*};
+use crate::{
+    levels::prelude::*,
+    log::prelude::*,
+    procgen::{
+        placements::{ChunkPlacements, Placement, PlacementContext},
+        prelude::*,
+    },
+    render::prelude::*,
+    utils::prelude::*,
+};
 
-/// Number of lights to spawn per chunk.
-const MIST_PER_CHUNK: usize = 1;
+/// [`Placement`]s of mist `T` in a chunk.
+pub(super) fn place_mist<T, A>(context: &PlacementContext<A>) -> Vec<Placement>
+where
+    T: MistWrapper + ProcGenerated + Visible,
+    A: ProcGenerated,
+{
+    let mut rng = context.rng::<T>();
+    context
+        .origins::<T>(&mut rng, context.biome.mist_per_chunk)
+        .into_iter()
+        .enumerate()
+        .map(|(index, origin)| Placement {
+            index,
+            pos: context.world_pos(origin),
+            choice: None,
+        })
+        .collect()
+}
 
-/// Spawn mist in a chunk.
+/// Spawn mist from [`ChunkPlacements`] of a chunk.
 pub(super) fn spawn_on_procgen_mist<T, A, B>(
     event: On<ProcGen<T>>,
     level: Single<Entity, With<B>>,
-    mut procgen_rng: Single<&mut WyRand, With<ProcGenRng>>,
+    placement_query: Query<&ChunkPlacements>,
     mut commands: Commands,
     mut object_cache: ResMut<ProcGenCache<T>>,
+    deltas: Res<ProcGenDeltas<T>>,
     mesh: Res<MistMeshHandle<T>>,
-    tile_data: Res<TileDataCache<A>>,
+    biomes: Res<BiomeDataCache<A>>,
+    seed: Res<WorldSeed>,
 ) where
     T: MistWrapper + ProcGenerated + Visible,
     A: ProcGenerated,
     B: Level,
 {
-    let world_pos = event.chunk_pos.as_vec2() * CHUNK_SIZE.as_vec2() * tile_data.tile_size;
+    let placements = placement_query
+        .get(event.entity)
+        .expect(ERR_INVALID_CHUNK_PLACEMENTS);
+    let biome = biomes.biome(&seed, event.chunk_pos);
 
-    // Choose a number of target chunk tile origins to determine spawn positions
-    let target_origins: Vec<(u32, u32)> = (0..CHUNK_SIZE.x)
-        .flat_map(|x| (0..CHUNK_SIZE.y).map(move |y| (x, y)))
-        .collect();
-    let target_origins: Vec<Vec2> = target_origins
-        .sample(&mut procgen_rng, MIST_PER_CHUNK)
-        .map(|&(x, y)| Vec2::new(x as f32, y as f32))
-        .collect();
+    for placement in placements.get::<T>() {
+        // Apply delta from previous generations of this chunk
+        let pos = match deltas.get(event.chunk_pos, placement.index) {
+            Some(ProcGenDelta::Removed) => continue,
+            Some(ProcGenDelta::Changed { pos, .. }) => *pos,
+            Some(ProcGenDelta::Spawned { .. }) | None => placement.pos,
+        };
 
-    for origin in target_origins {
-        // Spawn entity in chosen tile and store in `object_cache`
-        let target_pos = world_pos + origin * tile_data.tile_size;
-        let entity = T::new(mesh.handle.clone()).spawn(&mut commands, target_pos);
+        // Spawn entity at placement and store in `object_cache`
+        let mut mist = T::new(mesh.handle.clone());
+        if let Some((rgb, a)) = biome.mist_color {
+            mist = mist.with_color(color_from_rgba(&rgb, a));
+        }
+        let entity = mist.spawn(&mut commands, pos);
+        commands.entity(entity).insert(ProcGenOrigin {
+            chunk_pos: event.chunk_pos,
+            index: placement.index,
+            pos: placement.pos,
+        });
         object_cache.chunk_positions.insert(entity, event.chunk_pos);
 
         // Add entity to level so that level handles despawning
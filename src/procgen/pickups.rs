@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use rand::RngExt as _;
+
+use crate::{
+    levels::prelude::*,
+    log::prelude::*,
+    procgen::{
+        placements::{ChunkPlacements, Placement, PlacementContext},
+        prelude::*,
+    },
+};
+
+/// [`PickupKind`]s that [`Placement::choice`] of a [`Pickup`] refers to.
+const PICKUP_KINDS: [PickupKind; 2] = [
+    PickupKind::Heal(PICKUP_HEAL_AMOUNT),
+    PickupKind::MaxHealth(PICKUP_MAX_HEALTH_AMOUNT),
+];
+
+/// [`Placement`]s of [`Pickup`]s in a chunk.
+///
+/// Each [`Pickup`] is a [`PickupKind::MaxHealth`] with [`PICKUP_MAX_HEALTH_CHANCE`] and a [`PickupKind::Heal`] otherwise.
+/// Stamped chunks are skipped to avoid placing pickups on top of them.
+pub(super) fn place_pickups<A>(context: &PlacementContext<A>) -> Vec<Placement>
+where
+    A: ProcGenerated,
+{
+    if context.stamp.is_some() {
+        return Vec::new();
+    }
+
+    let mut rng = context.rng::<Pickup>();
+    context
+        .random_origins(&mut rng, context.biome.pickups_per_chunk)
+        .into_iter()
+        .enumerate()
+        .map(|(index, origin)| Placement {
+            index,
+            pos: context.world_pos(origin),
+            choice: Some(usize::from(rng.random_bool(PICKUP_MAX_HEALTH_CHANCE))),
+        })
+        .collect()
+}
+
+/// Spawn [`Pickup`]s from [`ChunkPlacements`] of a chunk.
+pub(super) fn spawn_on_procgen_pickups<B>(
+    event: On<ProcGen<Pickup>>,
+    level: Single<Entity, With<B>>,
+    placement_query: Query<&ChunkPlacements>,
+    mut commands: Commands,
+    mut object_cache: ResMut<ProcGenCache<Pickup>>,
+    deltas: Res<ProcGenDeltas<Pickup>>,
+) where
+    B: Level,
+{
+    let placements = placement_query
+        .get(event.entity)
+        .expect(ERR_INVALID_CHUNK_PLACEMENTS);
+
+    for placement in placements.get::<Pickup>() {
+        let Some(kind) = placement.choice.and_then(|i| PICKUP_KINDS.get(i).copied()) else {
+            continue;
+        };
+
+        // Apply delta from previous generations of this chunk
+        let pos = match deltas.get(event.chunk_pos, placement.index) {
+            Some(ProcGenDelta::Removed) => continue,
+            Some(ProcGenDelta::Changed { pos, .. }) => *pos,
+            Some(ProcGenDelta::Spawned { .. }) | None => placement.pos,
+        };
+
+        // Spawn entity at placement and store in `object_cache`
+        let entity = kind.spawn(&mut commands, pos);
+        commands.entity(entity).insert(ProcGenOrigin {
+            chunk_pos: event.chunk_pos,
+            index: placement.index,
+            pos: placement.pos,
+        });
+        object_cache.chunk_positions.insert(entity, event.chunk_pos);
+
+        // Add entity to level so that level handles despawning
+        commands.entity(*level).add_child(entity);
+    }
+}
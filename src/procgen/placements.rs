@@ -0,0 +1,103 @@
+//! Placements of procedurally generated objects that are computed with the tiles of a chunk.
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_prng::WyRand;
+use rand::seq::IndexedRandom as _;
+
+use crate::{levels::prelude::*, procgen::prelude::*};
+
+/// Placement of a single procedurally generated object in a chunk.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Placement {
+    /// Position in the order the object was generated in for its chunk.
+    ///
+    /// This is used as [`ProcGenOrigin::index`].
+    pub(super) index: usize,
+    /// Generated world position.
+    pub(super) pos: Vec2,
+    /// Index of a random choice, e.g. an archetype, a prop or a display variant.
+    pub(super) choice: Option<usize>,
+}
+
+/// [`Placement`]s of all procedurally generated objects in a chunk.
+///
+/// This is inserted into chunk containers once their [`ChunkTask`](crate::procgen::chunks::ChunkTask) has been applied.
+#[derive(Component, Default, Debug)]
+pub(super) struct ChunkPlacements(HashMap<&'static str, Vec<Placement>>);
+impl ChunkPlacements {
+    /// Insert `placements` of `T`.
+    pub(super) fn insert<T>(&mut self, placements: Vec<Placement>)
+    where
+        T: ProcGenerated,
+    {
+        self.0.insert(T::type_path(), placements);
+    }
+
+    /// [`Placement`]s of `T`.
+    pub(super) fn get<T>(&self) -> &[Placement]
+    where
+        T: ProcGenerated,
+    {
+        self.0.get(T::type_path()).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Data of a chunk that [`Placement`]s are computed from.
+///
+/// This is owned so that it can be moved to the [`AsyncComputeTaskPool`](bevy::tasks::AsyncComputeTaskPool).
+pub(super) struct PlacementContext<T>
+where
+    T: ProcGenerated,
+{
+    pub(super) chunk_pos: IVec2,
+    pub(super) seed: WorldSeed,
+    pub(super) biome: Biome,
+    pub(super) config: ProcGenDataCache<T>,
+    pub(super) tile_size: f32,
+    /// [`Map`] stamped into this chunk.
+    pub(super) stamp: Option<Map>,
+}
+impl<T> PlacementContext<T>
+where
+    T: ProcGenerated,
+{
+    /// Rng for `A` in this chunk.
+    pub(super) fn rng<A>(&self) -> WyRand
+    where
+        A: ProcGenerated,
+    {
+        self.seed.chunk_rng::<A>(self.chunk_pos)
+    }
+
+    /// World position of the tile at tile origin `origin` within this chunk.
+    pub(super) fn world_pos(&self, origin: Vec2) -> Vec2 {
+        (self.chunk_pos.as_vec2() * self.config.chunk_size.as_vec2() + origin) * self.tile_size
+    }
+
+    /// Tile origins of [`MapObject`]s of `kind` if this chunk is stamped.
+    pub(super) fn kind_origins(&self, kind: MapObjectKind) -> Option<Vec<Vec2>> {
+        let map = self.stamp.as_ref()?;
+        Some(map.positions(kind).iter().map(UVec2::as_vec2).collect())
+    }
+
+    /// Tile origins of `A` in this chunk.
+    ///
+    /// Stamped chunks use their spawn points, otherwise `amount` random tile origins are chosen.
+    pub(super) fn origins<A>(&self, rng: &mut WyRand, amount: usize) -> Vec<Vec2>
+    where
+        A: ProcGenerated,
+    {
+        A::map_object()
+            .and_then(|kind| self.kind_origins(kind))
+            .unwrap_or_else(|| self.random_origins(rng, amount))
+    }
+
+    /// `amount` random tile origins in this chunk.
+    pub(super) fn random_origins(&self, rng: &mut WyRand, amount: usize) -> Vec<Vec2> {
+        self.config
+            .tile_origins()
+            .sample(rng, amount)
+            .map(|&(x, y)| Vec2::new(x as f32, y as f32))
+            .collect()
+    }
+}
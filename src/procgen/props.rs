@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use rand::RngExt as _;
+
+use crate::{
+    levels::prelude::*,
+    log::prelude::*,
+    procgen::{
+        placements::{ChunkPlacements, Placement, PlacementContext},
+        prelude::*,
+    },
+};
+
+/// [`Placement`]s of randomly placed [`Prop`]s in a chunk.
+///
+/// Each [`Prop`] gets a random index out of `props` as [`Placement::choice`].
+/// Stamped chunks are skipped to avoid placing props on top of them.
+pub(super) fn place_props<A>(context: &PlacementContext<A>, props: usize) -> Vec<Placement>
+where
+    A: ProcGenerated,
+{
+    if context.stamp.is_some() || props == 0 {
+        return Vec::new();
+    }
+
+    let mut rng = context.rng::<Prop>();
+    context
+        .random_origins(&mut rng, context.biome.props_per_chunk)
+        .into_iter()
+        .enumerate()
+        .map(|(index, origin)| Placement {
+            index,
+            pos: context.world_pos(origin),
+            choice: Some(rng.random_range(0..props)),
+        })
+        .collect()
+}
+
+/// Spawn [`Prop`]s from [`ChunkPlacements`] of a chunk.
+pub(super) fn spawn_on_procgen_props<A, B>(
+    event: On<ProcGen<Prop>>,
+    level: Single<Entity, With<B>>,
+    placement_query: Query<&ChunkPlacements>,
+    mut commands: Commands,
+    mut object_cache: ResMut<ProcGenCache<Prop>>,
+    props: Res<PropDataCache<A>>,
+) where
+    A: ProcGenerated,
+    B: Level,
+{
+    let placements = placement_query
+        .get(event.entity)
+        .expect(ERR_INVALID_CHUNK_PLACEMENTS);
+
+    for placement in placements.get::<Prop>() {
+        let Some(prop) = placement.choice.and_then(|i| props.random.get(i)) else {
+            continue;
+        };
+
+        // Spawn entity at placement and store in `object_cache`
+        let entity = prop.spawn(&mut commands, placement.pos);
+        object_cache.chunk_positions.insert(entity, event.chunk_pos);
+
+        // Add entity to level so that level handles despawning
+        commands.entity(*level).add_child(entity);
+    }
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use bevy_prng::WyRand;
+use bevy_rand::global::GlobalRng;
+use rand::{RngExt as _, SeedableRng as _};
+
+use crate::{log::prelude::*, procgen::prelude::*, utils::prelude::*};
+
+/// Command line argument that is followed by the world seed.
+const WORLD_SEED_ARG: &str = "--seed";
+/// Environment variable containing the world seed.
+const WORLD_SEED_ENV: &str = "SLIMY_MIST_SEED";
+
+/// Seed that all procedurally generated content is derived from.
+///
+/// This is read from [`WORLD_SEED_ARG`] or [`WORLD_SEED_ENV`] and falls back to a random seed.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct WorldSeed(pub(crate) u64);
+impl WorldSeed {
+    /// Seed for `T` that is independent of chunk positions.
+    pub(crate) fn seed<T>(&self) -> u64
+    where
+        T: ProcGenerated,
+    {
+        // NOTE: We are using the type path since it is stable across runs, unlike `TypeId`.
+        self.0 ^ hash_str(T::type_path())
+    }
+
+    /// Seed for `T` in the chunk at `chunk_pos`.
+    pub(crate) fn chunk_seed<T>(&self, chunk_pos: IVec2) -> u64
+    where
+        T: ProcGenerated,
+    {
+        hash_2d(self.seed::<T>(), chunk_pos.x, chunk_pos.y)
+    }
+
+    /// Rng for `T` in the chunk at `chunk_pos`.
+    ///
+    /// This makes chunk contents independent of the order that chunks are generated in.
+    pub(crate) fn chunk_rng<T>(&self, chunk_pos: IVec2) -> WyRand
+    where
+        T: ProcGenerated,
+    {
+        WyRand::seed_from_u64(self.chunk_seed::<T>(chunk_pos))
+    }
+
+    /// Rng for the [`ForkedRng`] `T`.
+    pub(crate) fn forked_rng<T>(&self) -> WyRand
+    where
+        T: ForkedRng,
+    {
+        // NOTE: We are using the type path since it is stable across runs, unlike `TypeId`.
+        WyRand::seed_from_u64(self.0 ^ hash_str(T::type_path()))
+    }
+}
+
+/// Seed from [`WORLD_SEED_ARG`] or [`WORLD_SEED_ENV`].
+///
+/// Warns if the seed is not a valid [`u64`].
+fn seed_from_args_or_env() -> Option<u64> {
+    let text = std::env::args()
+        .skip_while(|a| a != WORLD_SEED_ARG)
+        .nth(1)
+        .or_else(|| std::env::var(WORLD_SEED_ENV).ok())?;
+    text.trim()
+        .parse()
+        .inspect_err(|_| warn!("{}", WARN_INVALID_WORLD_SEED))
+        .ok()
+}
+
+/// Insert [`WorldSeed`] from arguments, the environment or [`GlobalRng`].
+pub(super) fn insert_world_seed(
+    mut global: Single<&mut WyRand, With<GlobalRng>>,
+    mut commands: Commands,
+) {
+    let seed = seed_from_args_or_env().unwrap_or_else(|| global.random());
+    commands.insert_resource(WorldSeed(seed));
+}
+
+/// Reseed [`ForkedRng`] `T` from [`WorldSeed`].
+///
+/// This makes gameplay randomness like wandering, animations and loot reproducible for a [`WorldSeed`].
+pub(crate) fn seed_rng<T>(mut rng: Single<&mut WyRand, With<T>>, seed: Res<WorldSeed>)
+where
+    T: ForkedRng,
+{
+    **rng = seed.forked_rng::<T>();
+}
@@ -0,0 +1,49 @@
+//! Hand-authored set pieces stamped into procedurally generated chunks.
+
+use std::marker::PhantomData;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use serde::Deserialize;
+
+use crate::{levels::prelude::*, procgen::prelude::*};
+
+/// [`MapLayout`] that is stamped into the chunk at `chunk_pos`.
+///
+/// Tiles of the first layer replace generated tiles, further layers are spawned on top.
+/// Objects replace randomly placed objects of the same kind.
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct MapStamp {
+    pub(crate) chunk_pos: (i32, i32),
+    pub(crate) map: MapLayout,
+}
+
+/// Stamp data deserialized from a ron file.
+#[derive(Deserialize, Asset, TypePath, Default)]
+pub(crate) struct StampData<T>
+where
+    T: ProcGenerated,
+{
+    #[serde(default)]
+    pub(crate) stamps: Vec<MapStamp>,
+    #[serde(skip)]
+    pub(crate) _phantom: PhantomData<T>,
+}
+
+/// Handle for [`StampData`].
+#[derive(Resource)]
+pub(crate) struct StampHandle<T>(pub(crate) Handle<StampData<T>>)
+where
+    T: ProcGenerated;
+
+/// Cache for [`StampData`]
+///
+/// This is to allow easier access.
+#[derive(Resource, Default, Clone)]
+pub(crate) struct StampDataCache<T>
+where
+    T: ProcGenerated,
+{
+    /// Resolved maps by chunk position.
+    pub(crate) maps: HashMap<IVec2, Map>,
+    pub(crate) _phantom: PhantomData<T>,
+}
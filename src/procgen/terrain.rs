@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+
+use crate::{images::prelude::*, log::prelude::*, procgen::prelude::*, utils::prelude::*};
+
+/// Frequency of terrain noise per tile.
+const TERRAIN_NOISE_FREQUENCY: f32 = 0.08;
+/// Number of octaves of terrain noise.
+const TERRAIN_NOISE_OCTAVES: u32 = 3;
+/// Whether the tile vertex at `pos` is grass.
+///
+/// Tile vertices are shared between neighboring tiles and chunks, which allows seamless transitions.
+/// The noise threshold is [`Biome::grass_threshold`] of the chunk containing the vertex, which keeps
+/// vertices on chunk borders consistent.
+fn is_grass<T>(
+    seed: &WorldSeed,
+    pos: IVec2,
+    biomes: &BiomeDataCache<T>,
+    config: &ProcGenDataCache<T>,
+) -> bool
+where
+    T: ProcGenerated,
+{
+    let chunk_pos = config.chunk_pos(pos);
+    fbm_2d(
+        seed.seed::<T>(),
+        pos.as_vec2() * TERRAIN_NOISE_FREQUENCY,
+        TERRAIN_NOISE_OCTAVES,
+    ) > biomes.biome(seed, chunk_pos).grass_threshold
+}
+
+/// Tile coordinates in the tile set for the tile at global tile position `pos`.
+///
+/// This uses marching squares on the four vertices of the tile to choose from [`TileDataCache`].
+/// Sets containing multiple variants of the same tile are chosen from by hashing `pos`.
+///
+/// Returns [`None`] if the required set is missing from [`TileDataCache`].
+pub(super) fn tile_coords<T>(
+    seed: &WorldSeed,
+    pos: IVec2,
+    tile_data: &TileDataCache<T>,
+    biomes: &BiomeDataCache<T>,
+    config: &ProcGenDataCache<T>,
+) -> Option<(usize, usize)>
+where
+    T: ProcGenerated,
+{
+    // NOTE: The order of these bits matches the order of corners described in `TileData`.
+    let mask = [IVec2::ZERO, IVec2::X, IVec2::ONE, IVec2::Y]
+        .iter()
+        .enumerate()
+        .fold(0u8, |mask, (i, offset)| {
+            mask | ((is_grass(seed, pos + offset, biomes, config) as u8) << i)
+        });
+
+    let (set, index) = match mask {
+        0b0000 => (&tile_data.full_dirt, None),
+        0b1111 => (&tile_data.full_grass, None),
+        // A single grass corner
+        0b0001 | 0b0010 | 0b0100 | 0b1000 => (
+            &tile_data.corner_outer_grass_to_dirt,
+            Some(mask.trailing_zeros() as usize),
+        ),
+        // A single dirt corner
+        0b1110 | 0b1101 | 0b1011 | 0b0111 => (
+            &tile_data.corner_outer_dirt_to_grass,
+            Some((!mask & 0b1111).trailing_zeros() as usize),
+        ),
+        // Two adjacent grass corners
+        0b0011 => (&tile_data.side_dirt_and_grass, Some(0)),
+        0b0110 => (&tile_data.side_dirt_and_grass, Some(1)),
+        0b1100 => (&tile_data.side_dirt_and_grass, Some(2)),
+        0b1001 => (&tile_data.side_dirt_and_grass, Some(3)),
+        // Two opposite grass corners
+        0b0101 => (&tile_data.diag_stripe_grass_in_dirt, Some(0)),
+        _ => (&tile_data.diag_stripe_grass_in_dirt, Some(1)),
+    };
+    let set = set.as_ref().filter(|s| !s.is_empty())?;
+
+    match index {
+        Some(index) => set.get(index).copied(),
+        None => set
+            .get(hash_2d(seed.seed::<T>(), pos.x, pos.y) as usize % set.len())
+            .copied(),
+    }
+}
+
+/// [`TileTextureIndex`](bevy_ecs_tilemap::prelude::TileTextureIndex) value from tile coordinates `(x, y)`.
+///
+/// Falls back to the first tile and warns if `coords` are [`None`].
+pub(super) fn tile_texture_index(coords: Option<(usize, usize)>, columns: u32) -> u32 {
+    let Some((x, y)) = coords else {
+        warn_once!("{}", WARN_INCOMPLETE_TILE_DATA);
+        return 0;
+    };
+    y as u32 * columns + x as u32
+}
@@ -18,11 +18,13 @@ pub(crate) mod prelude {
     pub(crate) use super::mist::{MistMeshHandle, MistWrapper, StandardMist};
     pub(crate) use super::palette::*;
     pub(crate) use super::particles::effects::{
-        BloodParticle, DeathParticle, DustTrailParticle, MeleeParticle,
+        BloodParticle, DeathParticle, DustTrailParticle, LevelUpParticle, MeleeParticle,
+        ProjectileTrailParticle, StatusParticle,
     };
     pub(crate) use super::particles::materials::{
         BloodParticleMaterial, DeathParticleMaterial, DustTrailParticleMaterial,
-        MeleeParticleMaterial, Particle2dMaterialHandle,
+        LevelUpParticleMaterial, MeleeParticleMaterial, Particle2dMaterialHandle,
+        ProjectileTrailParticleMaterial, StatusParticleMaterial,
     };
     pub(crate) use super::particles::{
         Particle, ParticleHandle, ParticleTimer, SpawnChildParticleOnce, SpawnParticleOnce,
@@ -51,6 +53,12 @@ impl Plugin for RenderPlugin {
             OnEnter(Screen::Gameplay),
             camera::center_camera_on_player.in_set(EnterGameplaySystems::Camera),
         );
+        for level in [ActiveLevel::Overworld, ActiveLevel::Interior] {
+            app.add_systems(
+                OnEnter(level),
+                camera::center_camera_on_player.in_set(EnterLevelSystems::Camera),
+            );
+        }
         app.add_systems(Startup, camera::spawn_camera);
         app.add_systems(
             Update,
@@ -67,12 +75,32 @@ impl Plugin for RenderPlugin {
         app.add_systems(
             PostUpdate,
             (
+                ysort::relative_sort::<Loot, OverworldProcGen>,
                 ysort::relative_sort::<Player, OverworldProcGen>,
+                ysort::relative_sort::<Prop, OverworldProcGen>,
                 ysort::relative_sort::<Slime, OverworldProcGen>,
+                ysort::relative_sort::<Villager, OverworldProcGen>,
             )
                 .after(EnterGameplaySystems::Images)
                 .before(TransformSystems::Propagate)
-                .run_if(in_state(ProcGenInit(true)).and(in_state(Screen::Gameplay))),
+                // NOTE: `ProcGenInit` is only reset via `NextState` when leaving the overworld, so it
+                //       can still be set while the `ProcGenCache` is already cleared.
+                .run_if(
+                    in_state(ProcGenInit(true))
+                        .and(in_state(ActiveLevel::Overworld))
+                        .and(in_state(Screen::Gameplay)),
+                ),
+        );
+        app.add_systems(
+            PostUpdate,
+            (
+                ysort::map_sort::<Loot, Interior>,
+                ysort::map_sort::<Player, Interior>,
+                ysort::map_sort::<Slime, Interior>,
+            )
+                .after(EnterGameplaySystems::Images)
+                .before(TransformSystems::Propagate)
+                .run_if(in_state(ActiveLevel::Interior)),
         );
     }
 }
@@ -4,7 +4,7 @@ use bevy::{color::palettes::tailwind, prelude::*};
 use bevy_fast_light::prelude::*;
 
 use crate::{
-    core::prelude::*, log::prelude::*, procgen::prelude::*, render::prelude::*,
+    core::prelude::*, levels::prelude::*, log::prelude::*, procgen::prelude::*, render::prelude::*,
     screens::prelude::*, utils::prelude::*,
 };
 
@@ -100,7 +100,11 @@ impl LightWrapper for StreetLight {
         self.0
     }
 }
-impl ProcGenerated for StreetLight {}
+impl ProcGenerated for StreetLight {
+    fn map_object() -> Option<MapObjectKind> {
+        Some(MapObjectKind::StreetLight)
+    }
+}
 impl Visible for StreetLight {}
 
 /// Seconds in a day.
@@ -3,7 +3,7 @@ use std::marker::PhantomData;
 use bevy::{color::palettes::tailwind, prelude::*};
 use bevy_fast_mist::prelude::*;
 
-use crate::{procgen::prelude::*, render::prelude::*};
+use crate::{levels::prelude::*, procgen::prelude::*, render::prelude::*};
 
 /// Wrapper for mist.
 pub(crate) trait MistWrapper
@@ -13,6 +13,7 @@ where
     type Inner: Bundle;
     fn into_inner(self) -> Self::Inner;
     fn new(mesh: Handle<Mesh>) -> Self;
+    fn with_color(self, color: Color) -> Self;
     fn spawn(&self, commands: &mut Commands, pos: Vec2) -> Entity {
         commands
             .spawn((
@@ -64,9 +65,17 @@ impl MistWrapper for StandardMist {
             Mesh2d(mesh),
         ))
     }
+    fn with_color(mut self, color: Color) -> Self {
+        self.0.0.color = color;
+        self
+    }
     fn into_inner(self) -> Self::Inner {
         self.0
     }
 }
-impl ProcGenerated for StandardMist {}
+impl ProcGenerated for StandardMist {
+    fn map_object() -> Option<MapObjectKind> {
+        Some(MapObjectKind::StandardMist)
+    }
+}
 impl Visible for StandardMist {}
@@ -5,6 +5,11 @@ use crate::utils::prelude::*;
 /// Outline [`Color`].
 pub(crate) const OUTLINE_COLOR: Color = color_from_rgb(RGB_PALETTE[0]);
 
+/// [`Color`] at `index` of [`RGB_PALETTE`].
+pub(crate) fn palette_color(index: usize) -> Option<Color> {
+    RGB_PALETTE.get(index).map(|rgb| color_from_rgb(rgb))
+}
+
 /// RGB color palette as [`u8`] arrays.
 ///
 /// This is a slightly modified version of [Otterisk 96](https://lospec.com/palette-list/otterisk-96) by [Otterisk](https://lospec.com/otterisk).
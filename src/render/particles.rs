@@ -19,14 +19,12 @@ impl Plugin for ParticlesPlugin {
             Particle2dMaterialPlugin::<BloodParticleMaterial>::default(),
             Particle2dMaterialPlugin::<DeathParticleMaterial>::default(),
             Particle2dMaterialPlugin::<DustTrailParticleMaterial>::default(),
+            Particle2dMaterialPlugin::<LevelUpParticleMaterial>::default(),
             Particle2dMaterialPlugin::<MeleeParticleMaterial>::default(),
+            Particle2dMaterialPlugin::<ProjectileTrailParticleMaterial>::default(),
+            Particle2dMaterialPlugin::<StatusParticleMaterial>::default(),
         ));
 
-        app.add_systems(
-            OnEnter(Screen::Gameplay),
-            effects::add_dust_trail::<Player, { AnimationAction::WALK }>
-                .after(EnterGameplaySystems::Levels),
-        );
         app.add_systems(
             Update,
             effects::toggle_dust_trail::<Player>.run_if(in_state(Screen::Gameplay)),
@@ -36,10 +34,20 @@ impl Plugin for ParticlesPlugin {
             tick_component_timers::<ParticleTimer>.in_set(AppSystems::TickTimers),
         );
 
+        app.add_observer(effects::on_add_dust_trail::<Player, { AnimationAction::WALK }>);
         app.add_observer(on_spawn_child_particle_once::<BloodParticle, BloodParticleMaterial>);
         app.add_observer(on_spawn_particle_once::<DeathParticle, DeathParticleMaterial, Overworld>);
+        app.add_observer(on_spawn_particle_once::<DeathParticle, DeathParticleMaterial, Interior>);
+        app.add_observer(
+            on_spawn_particle_once::<DustTrailParticle, DustTrailParticleMaterial, Overworld>,
+        );
+        app.add_observer(
+            on_spawn_particle_once::<DustTrailParticle, DustTrailParticleMaterial, Interior>,
+        );
         app.add_observer(on_toggle_particle::<DustTrailParticle>);
+        app.add_observer(on_spawn_child_particle_once::<LevelUpParticle, LevelUpParticleMaterial>);
         app.add_observer(on_spawn_child_particle_once::<MeleeParticle, MeleeParticleMaterial>);
+        app.add_observer(on_spawn_child_particle_once::<StatusParticle, StatusParticleMaterial>);
     }
 }
 
@@ -24,18 +24,36 @@ impl Particle for DeathParticle {}
 pub(crate) struct DustTrailParticle(pub(crate) AnimationAction);
 impl Particle for DustTrailParticle {}
 
+/// Marker component for [`Experience`] level up particles.
+#[derive(Component, Default)]
+pub(crate) struct LevelUpParticle;
+impl Particle for LevelUpParticle {}
+
 /// Marker component for [`Attack::Melee`] particles.
 #[derive(Component, Default)]
 pub(crate) struct MeleeParticle;
 impl Particle for MeleeParticle {}
 
+/// Marker component for [`Projectile`] trail particles.
+#[derive(Component, Default)]
+pub(crate) struct ProjectileTrailParticle;
+impl Particle for ProjectileTrailParticle {}
+
+/// Marker component for [`StatusEffect`] particles.
+#[derive(Component, Default)]
+pub(crate) struct StatusParticle;
+impl Particle for StatusParticle {}
+
 /// Interval for [`DustTrailParticle`].
 const DUST_TRAIL_SECS: f32 = 0.5;
 
-/// Add [`DustTrailParticle`].
-pub(super) fn add_dust_trail<T, const ANIMATION_ACTION: u8>(
-    base_query: Query<(), With<AnimationBase>>,
-    query: Query<&Children, With<T>>,
+/// Add [`DustTrailParticle`] to every added [`AnimationBase`] of `T`.
+///
+/// This also adds it again if `T` is respawned.
+pub(super) fn on_add_dust_trail<T, const ANIMATION_ACTION: u8>(
+    event: On<Add, AnimationBase>,
+    base_query: Query<&ChildOf, With<AnimationBase>>,
+    character_query: Query<(), With<T>>,
     mut commands: Commands,
     cel_size: Res<CelSize<T>>,
     material: Res<Particle2dMaterialHandle<DustTrailParticleMaterial>>,
@@ -43,28 +61,28 @@ pub(super) fn add_dust_trail<T, const ANIMATION_ACTION: u8>(
 ) where
     T: Visible,
 {
-    let y_offset = cel_size.size.y as f32 / 2.;
-
-    for children in query {
-        let child = children
-            .iter()
-            .find(|e| base_query.contains(*e))
-            .expect(ERR_INVALID_CHILDREN);
-        commands.entity(child).with_child((
-            DustTrailParticle(
-                AnimationAction::try_from(ANIMATION_ACTION).expect(ERR_INVALID_ENUM_PRIMITVE),
-            ),
-            ParticleTimer(Timer::from_seconds(DUST_TRAIL_SECS, TimerMode::Repeating)),
-            ParticleSpawner(material.0.clone()),
-            NoAutoAabb,
-            ParticleSpawnerState {
-                active: false,
-                ..default()
-            },
-            ParticleEffectHandle(particle.handle.clone()),
-            Transform::from_translation(Vec3::new(0., -y_offset, -LAYER_Z_DELTA)),
-        ));
+    let Ok(child_of) = base_query.get(event.entity) else {
+        return;
+    };
+    if !character_query.contains(child_of.parent()) {
+        return;
     }
+
+    let y_offset = cel_size.size.y as f32 / 2.;
+    commands.entity(event.entity).with_child((
+        DustTrailParticle(
+            AnimationAction::try_from(ANIMATION_ACTION).expect(ERR_INVALID_ENUM_PRIMITVE),
+        ),
+        ParticleTimer(Timer::from_seconds(DUST_TRAIL_SECS, TimerMode::Repeating)),
+        ParticleSpawner(material.0.clone()),
+        NoAutoAabb,
+        ParticleSpawnerState {
+            active: false,
+            ..default()
+        },
+        ParticleEffectHandle(particle.handle.clone()),
+        Transform::from_translation(Vec3::new(0., -y_offset, -LAYER_Z_DELTA)),
+    ));
 }
 
 /// Minimum fraction of [`WalkSpeed`] for which to activate [`Particle`]s.
@@ -34,6 +34,15 @@ impl Particle2dMaterial for DustTrailParticleMaterial {
     }
 }
 
+/// [Particle2dMaterial] for [LevelUpParticle](crate::render::prelude::LevelUpParticle).
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone, Default)]
+pub(crate) struct LevelUpParticleMaterial {}
+impl Particle2dMaterial for LevelUpParticleMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/particles/level_up.wgsl".into()
+    }
+}
+
 /// [Particle2dMaterial] for [MeleeParticle](crate::render::prelude::MeleeParticle).
 #[derive(Asset, TypePath, AsBindGroup, Debug, Clone, Default)]
 pub(crate) struct MeleeParticleMaterial {}
@@ -42,3 +51,21 @@ impl Particle2dMaterial for MeleeParticleMaterial {
         "shaders/particles/melee.wgsl".into()
     }
 }
+
+/// [Particle2dMaterial] for [ProjectileTrailParticle](crate::render::prelude::ProjectileTrailParticle).
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone, Default)]
+pub(crate) struct ProjectileTrailParticleMaterial {}
+impl Particle2dMaterial for ProjectileTrailParticleMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/particles/projectile_trail.wgsl".into()
+    }
+}
+
+/// [Particle2dMaterial] for [StatusParticle](crate::render::prelude::StatusParticle).
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone, Default)]
+pub(crate) struct StatusParticleMaterial {}
+impl Particle2dMaterial for StatusParticleMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/particles/status.wgsl".into()
+    }
+}
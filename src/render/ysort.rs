@@ -51,3 +51,36 @@ pub(super) fn relative_sort<T, A>(
             sort.0 - (relative_y - texture_offset + total_y_offset) / scale_divisor;
     }
 }
+
+/// Y-sort `T` [`Entity`]s within the bounds of the [`Map`] of [`Level`] `L`.
+pub(super) fn map_sort<T, L>(
+    query: Query<
+        (
+            &mut Transform,
+            &YSort,
+            Option<&YSortYOffset>,
+            Option<&AnimationYOffset>,
+        ),
+        With<T>,
+    >,
+    cel_size: Res<CelSize<T>>,
+    map_data: Res<MapDataCache<L>>,
+) where
+    T: Visible,
+    L: Level,
+{
+    let map = &map_data.map;
+    // NOTE: Tiles are centered on their position, so the map starts half a tile below the origin.
+    let min_world_y = -map.tile_size / 2.;
+    let scale_divisor = map.size.y as f32 * map.tile_size * 2.;
+    let texture_offset = cel_size.size.y as f32 / 2.;
+
+    for (mut transform, sort, sort_y_offset, y_offset) in query {
+        let total_y_offset =
+            sort_y_offset.map_or(0., |offset| offset.0) + y_offset.map_or(0., |offset| offset.0);
+        let relative_y = transform.translation.y - min_world_y;
+
+        transform.translation.z =
+            sort.0 - (relative_y - texture_offset + total_y_offset) / scale_divisor;
+    }
+}
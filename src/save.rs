@@ -0,0 +1,373 @@
+//! Saving and loading of game state.
+
+pub(crate) mod prelude {
+    pub(crate) use super::{
+        LoadedSave, SaveSlot, load_game_on_click, save_and_enter_title_screen_on_click,
+        save_game_on_click, saved_items,
+    };
+}
+
+use std::{fs, path::PathBuf, time::Duration};
+
+use bevy::{audio::Volume, prelude::*};
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    characters::prelude::*, images::prelude::*, levels::prelude::*, log::prelude::*,
+    procgen::prelude::*, render::prelude::*, screens::prelude::*, ui::prelude::*,
+};
+
+pub(super) struct SavePlugin;
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(Screen::Gameplay),
+            apply_loaded_save.in_set(EnterGameplaySystems::LoadSave),
+        );
+        app.add_systems(OnExit(Screen::Gameplay), remove_loaded_save);
+
+        app.add_observer(on_save_game);
+    }
+}
+
+/// Version of [`SaveData`].
+///
+/// This has to be incremented on every incompatible change of [`SaveData`].
+const SAVE_VERSION: u32 = 8;
+
+/// Directory containing save files relative to the platform data directory.
+const SAVE_DIR: &str = "slimy_mist/saves";
+
+/// [`SAVE_DIR`] within the platform data directory.
+///
+/// Returns [`None`] if the data directory can not be resolved.
+// NOTE: There is no file system on wasm, so saving is not supported there.
+#[cfg(target_family = "wasm")]
+fn save_dir() -> Option<PathBuf> {
+    None
+}
+
+/// [`SAVE_DIR`] within the platform data directory.
+///
+/// Returns [`None`] if the data directory can not be resolved.
+#[cfg(not(target_family = "wasm"))]
+fn save_dir() -> Option<PathBuf> {
+    #[cfg(target_os = "android")]
+    let dir = bevy::android::ANDROID_APP
+        .get()
+        .and_then(|app| app.internal_data_path());
+    #[cfg(target_os = "windows")]
+    let dir = std::env::var_os("APPDATA").map(PathBuf::from);
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    let dir = std::env::var_os("HOME")
+        .map(|home| PathBuf::from(home).join("Library/Application Support"));
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "windows",
+        target_os = "macos",
+        target_os = "ios"
+    )))]
+    let dir = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")));
+    dir.map(|dir| dir.join(SAVE_DIR))
+}
+
+/// Slot that a save file is stored in.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum SaveSlot {
+    /// Written when quitting to the title screen.
+    Auto,
+    /// Written from the pause menu.
+    Manual,
+}
+impl SaveSlot {
+    /// Path of the save file.
+    ///
+    /// Returns [`None`] if saving is not supported on this platform.
+    fn path(&self) -> Option<PathBuf> {
+        let file = match self {
+            SaveSlot::Auto => "autosave.ron",
+            SaveSlot::Manual => "save.ron",
+        };
+        save_dir().map(|dir| dir.join(file))
+    }
+
+    /// Whether a save file exists for this slot.
+    pub(crate) fn exists(&self) -> bool {
+        self.path().is_some_and(|path| path.is_file())
+    }
+
+    /// Read and deserialize [`SaveData`] from this slot.
+    ///
+    /// Warns and returns [`None`] if the save file is invalid or has an incompatible version.
+    fn read(&self) -> Option<SaveData> {
+        let data = self
+            .path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|s| ron::from_str::<SaveData>(&s).ok());
+        let Some(data) = data else {
+            warn!("{}", WARN_INVALID_SAVE);
+            return None;
+        };
+        if data.version != SAVE_VERSION {
+            warn!("{}", WARN_INCOMPATIBLE_SAVE_VERSION);
+            return None;
+        }
+        Some(data)
+    }
+
+    /// Serialize and write `data` to this slot.
+    ///
+    /// Warns if writing fails.
+    fn write(&self, data: &SaveData) {
+        let Some(path) = self.path() else {
+            warn!("{}", WARN_FAILED_SAVE);
+            return;
+        };
+        let result = ron::ser::to_string_pretty(data, PrettyConfig::default())
+            .map_err(std::io::Error::other)
+            .and_then(|s| {
+                path.parent()
+                    .map_or(Ok(()), fs::create_dir_all)
+                    .and_then(|_| fs::write(&path, s))
+            });
+        if result.is_err() {
+            warn!("{}", WARN_FAILED_SAVE);
+        }
+    }
+}
+
+/// Saved settings.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub(crate) struct SavedSettings {
+    pub(crate) global_volume: f32,
+    pub(crate) joystick: bool,
+}
+
+/// Game state serialized to a save file.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub(crate) struct SaveData {
+    pub(crate) version: u32,
+    pub(crate) world_seed: u64,
+    pub(crate) player_pos: Vec2,
+    pub(crate) player_health: f32,
+    /// [`Health::max`] without the bonus of [`Equipment`].
+    pub(crate) player_max_health: f32,
+    /// Names of [`Item`]s in the [`Inventory`].
+    pub(crate) player_inventory: Vec<String>,
+    /// Names of [`Item`]s in the [`Equipment`].
+    pub(crate) player_equipment: Vec<String>,
+    /// [`Experience::level`] of the [`Player`].
+    pub(crate) player_level: usize,
+    /// [`Experience::points`] of the [`Player`].
+    pub(crate) player_experience: f32,
+    /// [`LayerSelection`] of the [`Player`].
+    pub(crate) player_layers: Vec<Option<usize>>,
+    pub(crate) day_secs: f32,
+    pub(crate) slime_deltas: Vec<(IVec2, usize, ProcGenDelta)>,
+    pub(crate) villager_deltas: Vec<(IVec2, usize, ProcGenDelta)>,
+    pub(crate) light_deltas: Vec<(IVec2, usize, ProcGenDelta)>,
+    pub(crate) mist_deltas: Vec<(IVec2, usize, ProcGenDelta)>,
+    pub(crate) pickup_deltas: Vec<(IVec2, usize, ProcGenDelta)>,
+    pub(crate) loot_deltas: Vec<(IVec2, usize, ProcGenDelta)>,
+    pub(crate) settings: SavedSettings,
+}
+
+/// [`SaveData`] that has been loaded and is applied when entering [`Screen::Gameplay`].
+#[derive(Resource)]
+pub(crate) struct LoadedSave(pub(crate) SaveData);
+
+/// Write the current game state to [`SaveSlot`].
+#[derive(Event)]
+struct SaveGame(SaveSlot);
+
+/// Write [`SaveData`] on [`SaveGame`].
+fn on_save_game(
+    event: On<SaveGame>,
+    player: Single<(&Transform, &Health, &Inventory, &Equipment, &Experience), With<Player>>,
+    slime_query: Query<(&Transform, &ProcGenOrigin, Option<&Health>), With<Slime>>,
+    villager_query: Query<(&Transform, &ProcGenOrigin, Option<&Health>), With<Villager>>,
+    light_query: Query<(&Transform, &ProcGenOrigin, Option<&Health>), With<StreetLight>>,
+    mist_query: Query<(&Transform, &ProcGenOrigin, Option<&Health>), With<StandardMist>>,
+    pickup_query: Query<(&Transform, &ProcGenOrigin, Option<&Health>), With<Pickup>>,
+    loot_query: Query<(&Transform, &ProcGenOrigin, Option<&Health>), With<Loot>>,
+    day_timer: Res<DayTimer>,
+    level_return: Option<Res<LevelReturn>>,
+    global_volume: Res<GlobalVolume>,
+    joystick_state: Res<State<JoystickState<{ JoystickID::MOVEMENT }>>>,
+    seed: Res<WorldSeed>,
+    slime_deltas: Res<ProcGenDeltas<Slime>>,
+    villager_deltas: Res<ProcGenDeltas<Villager>>,
+    light_deltas: Res<ProcGenDeltas<StreetLight>>,
+    mist_deltas: Res<ProcGenDeltas<StandardMist>>,
+    pickup_deltas: Res<ProcGenDeltas<Pickup>>,
+    loot_deltas: Res<ProcGenDeltas<Loot>>,
+    item_data: Res<ItemDataCache<Player>>,
+    layer_selection: Option<Res<LayerSelection<Player>>>,
+) {
+    let (transform, health, inventory, equipment, experience) = player.into_inner();
+    // NOTE: Only the overworld is saved, so we are using the position to return to from other levels.
+    let player_pos = level_return.map_or(transform.translation.xy(), |r| r.0);
+    let data = SaveData {
+        version: SAVE_VERSION,
+        world_seed: seed.0,
+        player_pos,
+        player_health: health.current,
+        player_max_health: health.max - equipment.applied_max_health,
+        player_inventory: item_names(inventory.iter(), &item_data),
+        player_equipment: item_names(equipment.slots.values(), &item_data),
+        player_level: experience.level,
+        player_experience: experience.points,
+        player_layers: layer_selection.map_or_else(Vec::new, |s| s.selection.clone()),
+        day_secs: day_timer.elapsed_secs(),
+        slime_deltas: saved_deltas(&slime_deltas, &slime_query),
+        villager_deltas: saved_deltas(&villager_deltas, &villager_query),
+        light_deltas: saved_deltas(&light_deltas, &light_query),
+        mist_deltas: saved_deltas(&mist_deltas, &mist_query),
+        pickup_deltas: saved_deltas(&pickup_deltas, &pickup_query),
+        loot_deltas: saved_deltas(&loot_deltas, &loot_query),
+        settings: SavedSettings {
+            global_volume: global_volume.volume.to_linear(),
+            joystick: joystick_state.is_active(),
+        },
+    };
+    event.0.write(&data);
+}
+
+/// Entries of `deltas` including the current state of loaded objects from `query`.
+fn saved_deltas<T>(
+    deltas: &ProcGenDeltas<T>,
+    query: &Query<(&Transform, &ProcGenOrigin, Option<&Health>), With<T>>,
+) -> Vec<(IVec2, usize, ProcGenDelta)>
+where
+    T: ProcGenerated,
+{
+    let mut deltas = ProcGenDeltas::<T>::from_entries(deltas.entries());
+    for (transform, origin, health) in query {
+        deltas.record(origin, transform.translation.xy(), health);
+    }
+    deltas.entries()
+}
+
+/// Names of [`Item`]s at `indexes` in `item_data`.
+fn item_names<'a>(
+    indexes: impl Iterator<Item = &'a usize>,
+    item_data: &ItemDataCache<Player>,
+) -> Vec<String> {
+    indexes
+        .filter_map(|i| item_data.items.get(*i))
+        .map(|i| i.name.clone())
+        .collect()
+}
+
+/// Apply [`LoadedSave`] to [`Resource`]s and [`State`]s.
+///
+/// The player is spawned from [`LoadedSave`] in [`EnterGameplaySystems::Levels`].
+fn apply_loaded_save(
+    mut commands: Commands,
+    mut day_timer: ResMut<DayTimer>,
+    mut global_volume: ResMut<GlobalVolume>,
+    mut next_joystick_state: ResMut<NextState<JoystickState<{ JoystickID::MOVEMENT }>>>,
+    joystick_state: Res<State<JoystickState<{ JoystickID::MOVEMENT }>>>,
+    save: Option<Res<LoadedSave>>,
+) {
+    let Some(save) = save else {
+        return;
+    };
+    let data = &save.0;
+
+    commands.insert_resource(WorldSeed(data.world_seed));
+    commands.insert_resource(ProcGenDeltas::<Slime>::from_entries(
+        data.slime_deltas.clone(),
+    ));
+    commands.insert_resource(ProcGenDeltas::<Villager>::from_entries(
+        data.villager_deltas.clone(),
+    ));
+    commands.insert_resource(ProcGenDeltas::<StreetLight>::from_entries(
+        data.light_deltas.clone(),
+    ));
+    commands.insert_resource(ProcGenDeltas::<StandardMist>::from_entries(
+        data.mist_deltas.clone(),
+    ));
+    commands.insert_resource(ProcGenDeltas::<Pickup>::from_entries(
+        data.pickup_deltas.clone(),
+    ));
+    commands.insert_resource(ProcGenDeltas::<Loot>::from_entries(
+        data.loot_deltas.clone(),
+    ));
+    commands.insert_resource(LayerSelection::<Player>::from_selection(
+        data.player_layers.clone(),
+    ));
+    day_timer.set_elapsed(Duration::from_secs_f32(data.day_secs));
+    global_volume.volume = Volume::Linear(data.settings.global_volume);
+    if joystick_state.is_active() != data.settings.joystick {
+        (*next_joystick_state).set_if_neq(JoystickState::Toggled(data.settings.joystick));
+    }
+}
+
+/// [`Inventory`] and [`Equipment`] from [`SaveData`].
+///
+/// Warns about [`Item`]s that do not exist in `item_data`.
+pub(crate) fn saved_items(
+    data: &SaveData,
+    item_data: &ItemDataCache<Player>,
+) -> (Inventory, Equipment) {
+    let indexes = |names: &[String]| -> Vec<usize> {
+        names
+            .iter()
+            .filter_map(|name| {
+                let index = item_data.index(name);
+                if index.is_none() {
+                    warn!("{}", WARN_UNKNOWN_SAVED_ITEM);
+                }
+                index
+            })
+            .collect()
+    };
+    let inventory = Inventory(indexes(&data.player_inventory));
+    let slots = indexes(&data.player_equipment)
+        .into_iter()
+        .map(|i| (item_data.items[i].slot, i))
+        .collect();
+    (inventory, Equipment { slots, ..default() })
+}
+
+/// Remove [`LoadedSave`].
+fn remove_loaded_save(mut commands: Commands) {
+    commands.remove_resource::<LoadedSave>();
+}
+
+/// Load [`SaveSlot`] and enter [`Screen::Gameplay`] on [`Pointer`] click.
+pub(crate) fn load_game_on_click<const MANUAL: bool>(
+    _: On<Pointer<Click>>,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<Screen>>,
+) {
+    let slot = if MANUAL {
+        SaveSlot::Manual
+    } else {
+        SaveSlot::Auto
+    };
+    let Some(data) = slot.read() else {
+        return;
+    };
+    commands.insert_resource(LoadedSave(data));
+    (*next_state).set_if_neq(Screen::Gameplay);
+}
+
+/// Write the current game state to [`SaveSlot::Manual`] on [`Pointer`] click.
+pub(crate) fn save_game_on_click(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(SaveGame(SaveSlot::Manual));
+}
+
+/// Write the current game state to [`SaveSlot::Auto`] and enter [`Screen::Title`] on [`Pointer`] click.
+pub(crate) fn save_and_enter_title_screen_on_click(
+    _: On<Pointer<Click>>,
+    mut commands: Commands,
+    next_state: ResMut<NextState<Screen>>,
+) {
+    commands.trigger(SaveGame(SaveSlot::Auto));
+    enter_title_screen(next_state);
+}
@@ -5,6 +5,7 @@
 
 //! The game's main screen states and transitions between them.
 
+mod character_creator;
 mod gameplay;
 mod loading;
 mod splash;
@@ -13,8 +14,8 @@ pub(crate) mod prelude {
     pub(crate) use super::gameplay::EnterGameplaySystems;
     pub(crate) use super::splash::SplashAssets;
     pub(crate) use super::{
-        Screen, enter_gameplay_screen_on_click, enter_splash_screen, enter_title_screen,
-        enter_title_screen_on_click,
+        Screen, enter_character_creator_screen_on_click, enter_gameplay_screen_on_click,
+        enter_splash_screen, enter_title_screen, enter_title_screen_on_click,
     };
 }
 
@@ -26,6 +27,7 @@ pub(super) struct ScreensPlugin;
 impl Plugin for ScreensPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
+            character_creator::CharacterCreatorPlugin,
             gameplay::GameplayPlugin,
             loading::LoadingPlugin,
             splash::SplashPlugin,
@@ -46,6 +48,7 @@ pub(crate) enum Screen {
     LoadingCache,
     Splash,
     Title,
+    CharacterCreator,
     Gameplay,
 }
 impl Screen {
@@ -67,13 +70,22 @@ pub(crate) fn enter_title_screen(mut next_state: ResMut<NextState<Screen>>) {
     (*next_state).set_if_neq(Screen::Title);
 }
 
-/// Enter [`Screen::Title`] title on [`Pointer`] click.
+/// Enter [`Screen::Title`] on [`Pointer`] click.
 pub(crate) fn enter_title_screen_on_click(
     _: On<Pointer<Click>>,
     next_state: ResMut<NextState<Screen>>,
 ) {
     enter_title_screen(next_state);
 }
+
+/// Enter [`Screen::CharacterCreator`] on [`Pointer`] click.
+pub(crate) fn enter_character_creator_screen_on_click(
+    _: On<Pointer<Click>>,
+    mut next_state: ResMut<NextState<Screen>>,
+) {
+    (*next_state).set_if_neq(Screen::CharacterCreator);
+}
+
 /// Enter [`Screen::Gameplay`].
 pub(crate) fn enter_gameplay_screen_on_click(
     _: On<Pointer<Click>>,
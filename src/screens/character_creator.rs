@@ -0,0 +1,273 @@
+//! The screen state for creating the player character before a new game.
+
+use std::path::Path;
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+
+use crate::{
+    animations::prelude::*, characters::prelude::*, images::prelude::*, screens::prelude::*,
+    ui::prelude::*,
+};
+
+pub(super) struct CharacterCreatorPlugin;
+impl Plugin for CharacterCreatorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(Screen::CharacterCreator),
+            (insert_layer_selection, spawn_character_creator).chain(),
+        );
+
+        app.add_systems(
+            Update,
+            (update_preview, update_category_labels).run_if(
+                in_state(Screen::CharacterCreator).and(resource_changed::<LayerSelection<Player>>),
+            ),
+        );
+        app.add_systems(
+            Update,
+            enter_title_screen.run_if(
+                in_state(Screen::CharacterCreator).and(input_just_pressed(KeyCode::Escape)),
+            ),
+        );
+    }
+}
+
+/// Factor that the [`CelSize`] of the preview is scaled with.
+const PREVIEW_SCALE: f32 = 4.;
+
+/// Preview of [`DisplayLayers`] of the [`Player`].
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+enum CharacterPreview {
+    Base,
+    Floating,
+}
+
+/// Label marker that displays the selected layer of the [`LayerCategory`] at the contained index.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct LayerCategoryLabel(usize);
+
+/// Insert [`LayerSelection`] with default layers.
+fn insert_layer_selection(mut commands: Commands, data: Res<LayerDataCache<Player>>) {
+    commands.insert_resource(LayerSelection::new(&data));
+}
+
+/// Spawn character creator with a preview and a row for each selectable [`LayerCategory`].
+fn spawn_character_creator(
+    mut commands: Commands,
+    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    mut images: ResMut<Assets<Image>>,
+    animation_data: Res<AnimationDataCache<Player>>,
+    data: Res<LayerDataCache<Player>>,
+    font: Res<UiFontHandle>,
+) {
+    let meta = ImageMeta::from_layer_data_cache(&data, &mut images);
+    let cel_size = CelSize::from_animation_data(&animation_data, &meta).size;
+    let layout = atlas_layouts.add(TextureAtlasLayout::from_grid(
+        cel_size,
+        animation_data.atlas_columns as u32,
+        animation_data.atlas_rows as u32,
+        None,
+        None,
+    ));
+    // NOTE: The first cel of the idle animation is used since it faces the camera.
+    let index = animation_data
+        .idle_clips
+        .first()
+        .and_then(|c| c.sprite_coords.first())
+        .map_or(0, |(x, y)| y * animation_data.atlas_columns + x);
+    let atlas = TextureAtlas { layout, index };
+    commands.insert_resource(meta);
+
+    let button_start = button(
+        ButtonConfig::navigable()
+            .with_text("Start")
+            .with_header_font(font.0.clone()),
+        ButtonNodeConfig::round_big(),
+        enter_gameplay_screen_on_click,
+    );
+    let button_back = button(
+        ButtonConfig::navigable()
+            .with_text("Back")
+            .with_header_font(font.0.clone()),
+        ButtonNodeConfig::round_big(),
+        enter_title_screen_on_click,
+    );
+
+    let grid = commands
+        .spawn((
+            Name::new("Layer Category Grid"),
+            Node {
+                display: Display::Grid,
+                row_gap: px(BODY_FONT_SIZE),
+                grid_template_columns: RepeatedGridTrack::px(2, ROOT_MAX_ELEMENT_WIDTH_PX / 3.),
+                ..default()
+            },
+        ))
+        .with_children(|commands| {
+            for (index, category) in data.categories.iter().enumerate() {
+                if !category.is_selectable() {
+                    continue;
+                }
+                commands.spawn(category_label(font.0.clone(), category.name.clone()));
+                commands.spawn(category_widget(font.0.clone(), index));
+            }
+        })
+        .id();
+    let preview = commands
+        .spawn((
+            Name::new("Character Preview"),
+            Node {
+                width: px(cel_size.x as f32 * PREVIEW_SCALE),
+                height: px(cel_size.y as f32 * PREVIEW_SCALE),
+                ..default()
+            },
+            children![
+                preview_image(CharacterPreview::Base, atlas.clone()),
+                preview_image(CharacterPreview::Floating, atlas),
+            ],
+        ))
+        .id();
+
+    commands
+        .spawn((
+            root_widget("Character Creator"),
+            DespawnOnExit(Screen::CharacterCreator),
+        ))
+        .with_children(|commands| {
+            commands.spawn(header_widget("Create your character", font.0.clone()));
+            commands
+                .spawn((
+                    Name::new("Character Creator Content"),
+                    Node {
+                        align_items: AlignItems::Center,
+                        column_gap: px(BODY_FONT_SIZE * 2.),
+                        ..default()
+                    },
+                ))
+                .add_children(&[preview, grid]);
+            commands.spawn(button_start);
+            commands.spawn(button_back);
+        });
+}
+
+/// [`ImageNode`] for [`CharacterPreview`] that fills its parent.
+fn preview_image(preview: CharacterPreview, atlas: TextureAtlas) -> impl Bundle {
+    (
+        Name::new("Character Preview Image"),
+        preview,
+        ImageNode::from_atlas_image(Handle::default(), atlas),
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100),
+            height: percent(100),
+            ..default()
+        },
+    )
+}
+
+/// Label for a [`LayerCategory`].
+fn category_label(font: Handle<Font>, name: String) -> impl Bundle {
+    (
+        Node {
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::End,
+            ..default()
+        },
+        children![label_widget(name, font)],
+    )
+}
+
+/// Widget to cycle the selected layer of the [`LayerCategory`] at `index`.
+fn category_widget(font: Handle<Font>, index: usize) -> impl Bundle {
+    let button_previous = button(
+        ButtonConfig::navigable()
+            .with_text("<")
+            .with_body_font(font.clone()),
+        ButtonNodeConfig::circle_small(),
+        move |_: On<Pointer<Click>>,
+              mut selection: ResMut<LayerSelection<Player>>,
+              data: Res<LayerDataCache<Player>>| {
+            selection.cycle(&data, index, false);
+        },
+    );
+    let button_next = button(
+        ButtonConfig::navigable()
+            .with_text(">")
+            .with_body_font(font.clone()),
+        ButtonNodeConfig::circle_small(),
+        move |_: On<Pointer<Click>>,
+              mut selection: ResMut<LayerSelection<Player>>,
+              data: Res<LayerDataCache<Player>>| {
+            selection.cycle(&data, index, true);
+        },
+    );
+
+    (
+        Name::new("Layer Category Widget"),
+        Node {
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        children![
+            button_previous,
+            (
+                Name::new("Selected Layer"),
+                Node {
+                    padding: UiRect::horizontal(px(10)),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                children![(LayerCategoryLabel(index), label_widget("", font))],
+            ),
+            button_next,
+        ],
+    )
+}
+
+/// Recompose [`DisplayLayers`] from [`LayerSelection`] and display them in [`CharacterPreview`].
+fn update_preview(
+    mut preview_query: Query<(&mut ImageNode, &CharacterPreview)>,
+    mut images: ResMut<Assets<Image>>,
+    data: Res<LayerDataCache<Player>>,
+    meta: Res<ImageMeta<Player>>,
+    selection: Res<LayerSelection<Player>>,
+) {
+    let mut layers =
+        DisplayLayers::from_layer_data_cache(&data, Some(&selection), &meta, &mut images);
+    layers.add_outline(&mut images, &meta);
+
+    for (mut image_node, preview) in &mut preview_query {
+        match preview {
+            CharacterPreview::Base => image_node.image = layers.base.clone(),
+            CharacterPreview::Floating => {
+                if let Some(floating) = &layers.floating {
+                    image_node.image = floating.clone();
+                }
+            }
+        }
+    }
+}
+
+/// Update labels that display the selected layer of each [`LayerCategory`].
+///
+/// The file name of the layer is used as its name.
+fn update_category_labels(
+    mut label_query: Query<(&mut Text, &LayerCategoryLabel)>,
+    data: Res<LayerDataCache<Player>>,
+    selection: Res<LayerSelection<Player>>,
+) {
+    for (mut text, label) in &mut label_query {
+        let layer = data
+            .categories
+            .get(label.0)
+            .zip(selection.get(label.0).copied().flatten())
+            .and_then(|(category, i)| category.layers.get(i));
+        text.0 = layer
+            .and_then(|l| Path::new(l).file_stem())
+            .map_or("None".to_string(), |s| {
+                s.to_string_lossy().replace('-', " ")
+            });
+    }
+}
@@ -19,6 +19,7 @@ impl Plugin for GameplayPlugin {
             OnEnter(Screen::Gameplay),
             (
                 EnterGameplaySystems::Resources,
+                EnterGameplaySystems::LoadSave,
                 EnterGameplaySystems::Images,
                 EnterGameplaySystems::Animations,
                 EnterGameplaySystems::Levels,
@@ -40,6 +41,7 @@ impl Plugin for GameplayPlugin {
 #[derive(SystemSet, Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub(crate) enum EnterGameplaySystems {
     Resources,
+    LoadSave,
     Images,
     Animations,
     Levels,
@@ -49,32 +51,62 @@ pub(crate) enum EnterGameplaySystems {
 
 /// Insert [`Resource`]s
 fn insert_resources(mut commands: Commands) {
+    commands.init_resource::<CurrentBiome<OverworldProcGen>>();
     commands.init_resource::<DayTimer>();
     commands.init_resource::<DayUpdateTimer>();
+    commands.init_resource::<DoorOccupied>();
     commands.init_resource::<JoystickMap>();
     commands.init_resource::<MouseDrag>();
     commands.init_resource::<PointerBlockedByUi>();
     commands.init_resource::<PointerStartTimeSecs>();
     commands.init_resource::<ProcGenCache<OverworldProcGen>>();
     commands.init_resource::<ProcGenCache<Slime>>();
+    commands.init_resource::<ProcGenDeltas<Slime>>();
+    commands.init_resource::<ProcGenCache<Villager>>();
+    commands.init_resource::<ProcGenDeltas<Villager>>();
     commands.init_resource::<ProcGenCache<StreetLight>>();
+    commands.init_resource::<ProcGenDeltas<StreetLight>>();
     commands.init_resource::<ProcGenCache<StandardMist>>();
+    commands.init_resource::<ProcGenDeltas<StandardMist>>();
+    commands.init_resource::<ProcGenCache<Pickup>>();
+    commands.init_resource::<ProcGenDeltas<Pickup>>();
+    commands.init_resource::<ProcGenCache<Loot>>();
+    commands.init_resource::<ProcGenDeltas<Loot>>();
+    commands.init_resource::<ProcGenCache<Prop>>();
     commands.init_resource::<WorldUiHealthBarMap>();
 }
 
 /// Remove [`Resource`]s
 fn remove_resources(mut commands: Commands) {
+    commands.remove_resource::<BossFight>();
+    commands.remove_resource::<CurrentBiome<OverworldProcGen>>();
     commands.remove_resource::<DayTimer>();
     commands.remove_resource::<DayUpdateTimer>();
     commands.remove_resource::<DisplayLayers<Player>>();
     commands.remove_resource::<DisplayLayers<Slime>>();
+    commands.remove_resource::<DisplayLayers<Villager>>();
+    commands.remove_resource::<DisplayVariants<Villager>>();
+    commands.remove_resource::<DoorOccupied>();
     commands.remove_resource::<JoystickMap>();
+    commands.remove_resource::<LayerSelection<Player>>();
+    commands.remove_resource::<LevelReturn>();
     commands.remove_resource::<MouseDrag>();
     commands.remove_resource::<PointerBlockedByUi>();
     commands.remove_resource::<PointerStartTimeSecs>();
+    commands.remove_resource::<RespawnPoint>();
     commands.remove_resource::<ProcGenCache<OverworldProcGen>>();
     commands.remove_resource::<ProcGenCache<Slime>>();
+    commands.remove_resource::<ProcGenDeltas<Slime>>();
+    commands.remove_resource::<ProcGenCache<Villager>>();
+    commands.remove_resource::<ProcGenDeltas<Villager>>();
     commands.remove_resource::<ProcGenCache<StreetLight>>();
+    commands.remove_resource::<ProcGenDeltas<StreetLight>>();
     commands.remove_resource::<ProcGenCache<StandardMist>>();
+    commands.remove_resource::<ProcGenDeltas<StandardMist>>();
+    commands.remove_resource::<ProcGenCache<Pickup>>();
+    commands.remove_resource::<ProcGenDeltas<Pickup>>();
+    commands.remove_resource::<ProcGenCache<Loot>>();
+    commands.remove_resource::<ProcGenDeltas<Loot>>();
+    commands.remove_resource::<ProcGenCache<Prop>>();
     commands.remove_resource::<WorldUiHealthBarMap>();
 }
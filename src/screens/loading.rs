@@ -27,11 +27,26 @@ impl Plugin for LoadingPlugin {
                 .with_state_transition(Screen::Loading, Screen::LoadingCache),
             RonAssetPlugin::<AnimationData<Player>>::new(&["animation.ron"]),
             RonAssetPlugin::<AnimationData<Slime>>::new(&["animation.ron"]),
+            RonAssetPlugin::<AnimationData<Villager>>::new(&["animation.ron"]),
+            RonAssetPlugin::<ArchetypeData<Slime>>::new(&["archetypes.ron"]),
+            RonAssetPlugin::<AttackData<Player>>::new(&["attacks.ron"]),
+            RonAssetPlugin::<AttackData<Slime>>::new(&["attacks.ron"]),
+            RonAssetPlugin::<AttackData<Villager>>::new(&["attacks.ron"]),
+            RonAssetPlugin::<BiomeData<OverworldProcGen>>::new(&["biomes.ron"]),
             RonAssetPlugin::<CollisionData<Player>>::new(&["collision.ron"]),
             RonAssetPlugin::<CollisionData<Slime>>::new(&["collision.ron"]),
+            RonAssetPlugin::<CollisionData<Villager>>::new(&["collision.ron"]),
             RonAssetPlugin::<CreditsData>::new(&["credits.ron"]),
+            RonAssetPlugin::<ItemData<Player>>::new(&["items.ron"]),
             RonAssetPlugin::<LayerData<Player>>::new(&["layers.ron"]),
             RonAssetPlugin::<LayerData<Slime>>::new(&["layers.ron"]),
+            RonAssetPlugin::<LayerData<Villager>>::new(&["layers.ron"]),
+            RonAssetPlugin::<LootData<Slime>>::new(&["loot.ron"]),
+            RonAssetPlugin::<MapData<Interior>>::new(&["map.ron"]),
+            RonAssetPlugin::<ProcGenData<OverworldProcGen>>::new(&["procgen.ron"]),
+            RonAssetPlugin::<ProgressionData<Player>>::new(&["progression.ron"]),
+            RonAssetPlugin::<StampData<OverworldProcGen>>::new(&["stamps.ron"]),
+            RonAssetPlugin::<PropData<OverworldProcGen>>::new(&["props.ron"]),
             RonAssetPlugin::<TileData<OverworldProcGen>>::new(&["tiles.ron"]),
         ));
 
@@ -44,6 +59,10 @@ impl Plugin for LoadingPlugin {
                     "data/levels/overworld.assets.ron",
                 )
                 .load_collection::<OverworldAssets>()
+                .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
+                    "data/levels/interior.assets.ron",
+                )
+                .load_collection::<InteriorAssets>()
                 .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
                     "data/characters/player/male.assets.ron",
                 )
@@ -51,7 +70,11 @@ impl Plugin for LoadingPlugin {
                 .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
                     "data/characters/npc/slime.assets.ron",
                 )
-                .load_collection::<SlimeAssets>(),
+                .load_collection::<SlimeAssets>()
+                .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
+                    "data/characters/npc/villager.assets.ron",
+                )
+                .load_collection::<VillagerAssets>(),
         );
 
         app.add_systems(
@@ -71,12 +94,31 @@ impl Plugin for LoadingPlugin {
                 (
                     cache_animation_data_and_related::<Player>,
                     cache_animation_data_and_related::<Slime>,
+                    cache_animation_data_and_related::<Villager>,
+                    cache_archetype_data::<Slime>,
+                    cache_attack_data::<Player>,
+                    cache_attack_data::<Slime>,
+                    cache_attack_data::<Villager>,
+                    cache_biome_data::<OverworldProcGen>,
                     cache_collision_data_and_related::<Player>,
                     cache_collision_data_and_related::<Slime>,
+                    cache_collision_data_and_related::<Villager>,
                     cache_credits_data,
+                    cache_item_data::<Player>,
                     cache_layer_data::<Player>,
                     cache_layer_data::<Slime>,
-                    cache_tile_data_and_related::<OverworldProcGen>,
+                    cache_layer_data::<Villager>,
+                    cache_loot_data::<Slime>,
+                    cache_procgen_data::<OverworldProcGen>,
+                    cache_progression_data::<Player>,
+                    cache_prop_data::<OverworldProcGen>,
+                    cache_tile_data::<OverworldProcGen>,
+                ),
+                (
+                    cache_level_dimensions::<OverworldProcGen>,
+                    // NOTE: Interiors share the tile set of the overworld.
+                    cache_map_data::<Interior, OverworldProcGen>,
+                    cache_stamp_data::<OverworldProcGen>,
                 ),
                 enter_splash_screen,
             )
@@ -100,7 +142,10 @@ fn insert_material_handle_resources(
     mut blood_particle_materials: ResMut<Assets<BloodParticleMaterial>>,
     mut death_particle_materials: ResMut<Assets<DeathParticleMaterial>>,
     mut dust_trail_particle_materials: ResMut<Assets<DustTrailParticleMaterial>>,
+    mut level_up_particle_materials: ResMut<Assets<LevelUpParticleMaterial>>,
     mut melee_particle_materials: ResMut<Assets<MeleeParticleMaterial>>,
+    mut projectile_trail_particle_materials: ResMut<Assets<ProjectileTrailParticleMaterial>>,
+    mut status_particle_materials: ResMut<Assets<StatusParticleMaterial>>,
 ) {
     // `Particle2dMaterialHandle`
     commands.insert_resource(Particle2dMaterialHandle::<BloodParticleMaterial>(
@@ -112,9 +157,18 @@ fn insert_material_handle_resources(
     commands.insert_resource(Particle2dMaterialHandle::<DustTrailParticleMaterial>(
         dust_trail_particle_materials.add(DustTrailParticleMaterial::default()),
     ));
+    commands.insert_resource(Particle2dMaterialHandle::<LevelUpParticleMaterial>(
+        level_up_particle_materials.add(LevelUpParticleMaterial::default()),
+    ));
     commands.insert_resource(Particle2dMaterialHandle::<MeleeParticleMaterial>(
         melee_particle_materials.add(MeleeParticleMaterial::default()),
     ));
+    commands.insert_resource(Particle2dMaterialHandle::<ProjectileTrailParticleMaterial>(
+        projectile_trail_particle_materials.add(ProjectileTrailParticleMaterial::default()),
+    ));
+    commands.insert_resource(Particle2dMaterialHandle::<StatusParticleMaterial>(
+        status_particle_materials.add(StatusParticleMaterial::default()),
+    ));
 }
 
 /// Insert handle [`Resource`]s for [`Mesh`]s.
@@ -140,6 +194,30 @@ fn insert_handle_resources(mut commands: Commands, asset_server: Res<AssetServer
     commands.insert_resource(AnimationHandle::<Slime>(
         asset_server.load("data/characters/npc/slime.animation.ron"),
     ));
+    commands.insert_resource(AnimationHandle::<Villager>(
+        asset_server.load("data/characters/human/male.animation.ron"),
+    ));
+
+    // `ArchetypeData`
+    commands.insert_resource(ArchetypeHandle::<Slime>(
+        asset_server.load("data/characters/npc/slime.archetypes.ron"),
+    ));
+
+    // `AttackData`
+    commands.insert_resource(AttackHandle::<Player>(
+        asset_server.load("data/characters/human/male.attacks.ron"),
+    ));
+    commands.insert_resource(AttackHandle::<Slime>(
+        asset_server.load("data/characters/npc/slime.attacks.ron"),
+    ));
+    commands.insert_resource(AttackHandle::<Villager>(
+        asset_server.load("data/characters/npc/villager.attacks.ron"),
+    ));
+
+    // `BiomeData`
+    commands.insert_resource(BiomeHandle::<OverworldProcGen>(
+        asset_server.load("data/levels/overworld.biomes.ron"),
+    ));
 
     // `CollisionData`
     commands.insert_resource(CollisionHandle::<Player>(
@@ -148,17 +226,59 @@ fn insert_handle_resources(mut commands: Commands, asset_server: Res<AssetServer
     commands.insert_resource(CollisionHandle::<Slime>(
         asset_server.load("data/characters/npc/slime.collision.ron"),
     ));
+    commands.insert_resource(CollisionHandle::<Villager>(
+        asset_server.load("data/characters/human/male.collision.ron"),
+    ));
 
+    // `ItemData`
+    commands.insert_resource(ItemHandle::<Player>(
+        asset_server.load("data/characters/player/male.items.ron"),
+    ));
+
+    // `LayerData`
     commands.insert_resource(LayerHandle::<Player>(
         asset_server.load("data/characters/player/male.layers.ron"),
     ));
     commands.insert_resource(LayerHandle::<Slime>(
         asset_server.load("data/characters/npc/slime.layers.ron"),
     ));
+    commands.insert_resource(LayerHandle::<Villager>(
+        asset_server.load("data/characters/npc/villager.layers.ron"),
+    ));
+
+    // `LootData`
+    commands.insert_resource(LootHandle::<Slime>(
+        asset_server.load("data/characters/npc/slime.loot.ron"),
+    ));
+
+    // `MapData`
+    commands.insert_resource(MapHandle::<Interior>(
+        asset_server.load("data/levels/interior.map.ron"),
+    ));
 
     // `CreditsData`
     commands.insert_resource(CreditsHandle(asset_server.load("data/menus/credits.ron")));
 
+    // `ProcGenData`
+    commands.insert_resource(ProcGenHandle::<OverworldProcGen>(
+        asset_server.load("data/levels/overworld.procgen.ron"),
+    ));
+
+    // `ProgressionData`
+    commands.insert_resource(ProgressionHandle::<Player>(
+        asset_server.load("data/characters/player/male.progression.ron"),
+    ));
+
+    // `PropData`
+    commands.insert_resource(PropHandle::<OverworldProcGen>(
+        asset_server.load("data/levels/overworld.props.ron"),
+    ));
+
+    // `StampData`
+    commands.insert_resource(StampHandle::<OverworldProcGen>(
+        asset_server.load("data/levels/overworld.stamps.ron"),
+    ));
+
     // `TileData`
     commands.insert_resource(TileHandle::<OverworldProcGen>(
         asset_server.load("data/levels/overworld.tiles.ron"),
@@ -177,10 +297,22 @@ fn insert_handle_resources(mut commands: Commands, asset_server: Res<AssetServer
         handle: asset_server.load("data/particles/dust-trail.particle.ron"),
         ..default()
     });
+    commands.insert_resource(ParticleHandle::<LevelUpParticle> {
+        handle: asset_server.load("data/particles/level-up.particle.ron"),
+        ..default()
+    });
     commands.insert_resource(ParticleHandle::<MeleeParticle> {
         handle: asset_server.load("data/particles/melee.particle.ron"),
         ..default()
     });
+    commands.insert_resource(ParticleHandle::<ProjectileTrailParticle> {
+        handle: asset_server.load("data/particles/projectile-trail.particle.ron"),
+        ..default()
+    });
+    commands.insert_resource(ParticleHandle::<StatusParticle> {
+        handle: asset_server.load("data/particles/status.particle.ron"),
+        ..default()
+    });
 
     // `UiFontHandle` not needing a custom data struct
     commands.insert_resource(UiFontHandle(
@@ -206,6 +338,7 @@ fn cache_animation_data_and_related<T>(
         walk_clips: data.walk_clips.clone(),
         _run_clips: data.run_clips,
         jump_clips: data.jump_clips.clone(),
+        dash_clips: data.dash_clips.clone(),
         ..default()
     });
 
@@ -223,6 +356,11 @@ fn cache_animation_data_and_related<T>(
             audio_map.insert(clip.state, clip.audio_indexes.clone());
         }
     }
+    if let Some(dash_clips) = data.dash_clips.as_ref() {
+        for clip in dash_clips {
+            audio_map.insert(clip.state, clip.audio_indexes.clone());
+        }
+    }
     commands.insert_resource(AnimationAudioMap::<T> {
         map: audio_map,
         ..default()
@@ -232,6 +370,100 @@ fn cache_animation_data_and_related<T>(
     commands.remove_resource::<AnimationHandle<T>>();
 }
 
+/// Cache data from [`ArchetypeData`] in [`ArchetypeDataCache`].
+fn cache_archetype_data<T>(
+    mut commands: Commands,
+    mut data: ResMut<Assets<ArchetypeData<T>>>,
+    asset_server: Res<AssetServer>,
+    handle: Res<ArchetypeHandle<T>>,
+) where
+    T: Visible,
+{
+    let data = data
+        .remove(handle.0.id())
+        .expect(ERR_LOADING_ARCHETYPE_DATA);
+    let archetypes = data
+        .archetypes
+        .into_iter()
+        .map(|mut archetype| {
+            archetype.music_handle = archetype.music.as_ref().map(|m| asset_server.load(m));
+            archetype
+        })
+        .collect();
+    commands.insert_resource(ArchetypeDataCache::<T> {
+        archetypes,
+        ..default()
+    });
+
+    // Remove handle after caching since it is no longer needed
+    commands.remove_resource::<ArchetypeHandle<T>>();
+}
+
+/// Cache data from [`AttackData`] in [`AttackDataCache`].
+fn cache_attack_data<T>(
+    mut commands: Commands,
+    mut data: ResMut<Assets<AttackData<T>>>,
+    asset_server: Res<AssetServer>,
+    handle: Res<AttackHandle<T>>,
+) where
+    T: Visible,
+{
+    let data = data.remove(handle.0.id()).expect(ERR_LOADING_ATTACK_DATA);
+    // NOTE: Characters without attacks are valid, e.g. villagers are not hostile.
+    if data.damage_factor.is_none() {
+        warn_once!("{}", WARN_INVALID_ATTACK_DATA);
+    }
+    let attacks = data
+        .attacks
+        .into_iter()
+        .map(|mut attack| {
+            attack.particle_handle = attack.particle.as_ref().map(|p| asset_server.load(p));
+            attack.sound_handles = attack.sounds.iter().map(|s| asset_server.load(s)).collect();
+            for effect in &mut attack.effects {
+                effect.particle_handle = effect.particle.as_ref().map(|p| asset_server.load(p));
+            }
+            attack
+        })
+        .collect();
+    commands.insert_resource(AttackDataCache::<T> {
+        damage_factor: data.damage_factor.unwrap_or(1.),
+        attacks,
+        hit_reaction: HitReaction {
+            stun_secs: data.hit_stun_secs.unwrap_or_default(),
+            invulnerability_secs: data.invulnerability_secs.unwrap_or_default(),
+        },
+        ..default()
+    });
+
+    // Remove handle after caching since it is no longer needed
+    commands.remove_resource::<AttackHandle<T>>();
+}
+
+/// Cache data from [`BiomeData`] in [`BiomeDataCache`].
+fn cache_biome_data<T>(
+    mut commands: Commands,
+    mut data: ResMut<Assets<BiomeData<T>>>,
+    handle: Res<BiomeHandle<T>>,
+) where
+    T: ProcGenerated,
+{
+    let mut data = data.remove(handle.0.id()).expect(ERR_LOADING_BIOME_DATA);
+    if data.biomes.is_empty() {
+        warn_once!("{}", WARN_INCOMPLETE_BIOME_DATA);
+        data.biomes.push(Biome::default());
+    }
+    data.biomes
+        .sort_by(|a, b| a.max_noise.total_cmp(&b.max_noise));
+    commands.insert_resource(BiomeDataCache::<T> {
+        frequency: data.frequency,
+        biomes: data.biomes,
+        ..default()
+    });
+
+    // Remove handle after caching since it is no longer needed
+    commands.remove_resource::<BiomeHandle<T>>();
+}
+
 /// Cache data from [`CollisionData`] in [`CollisionDataCache`].
 fn cache_collision_data_and_related<T>(
     mut commands: Commands,
@@ -288,6 +520,30 @@ fn cache_credits_data(
     commands.remove_resource::<CreditsHandle>();
 }
 
+/// Cache data from [`ItemData`] in [`ItemDataCache`].
+fn cache_item_data<T>(
+    mut commands: Commands,
+    mut data: ResMut<Assets<ItemData<T>>>,
+    asset_server: Res<AssetServer>,
+    handle: Res<ItemHandle<T>>,
+) where
+    T: Visible,
+{
+    let data = data.remove(handle.0.id()).expect(ERR_LOADING_ITEM_DATA);
+    let items = data
+        .items
+        .into_iter()
+        .map(|mut item| {
+            item.image_handle = asset_server.load(&item.image);
+            item
+        })
+        .collect();
+    commands.insert_resource(ItemDataCache::<T> { items, ..default() });
+
+    // Remove handle after caching since it is no longer needed
+    commands.remove_resource::<ItemHandle<T>>();
+}
+
 /// Cache data from [`LayerData`] in [`LayerDataCache`].
 fn cache_layer_data<T>(
     mut commands: Commands,
@@ -298,11 +554,24 @@ fn cache_layer_data<T>(
     T: Visible,
 {
     let data = data.remove(handle.0.id()).expect(ERR_LOADING_LAYER_DATA);
+    let categories = data
+        .categories
+        .into_iter()
+        .map(|mut category| {
+            category.layer_handles = category
+                .layers
+                .iter()
+                .map(|l| asset_server.load(l))
+                .collect();
+            category
+        })
+        .collect();
     let base = data.base.iter().map(|l| asset_server.load(l)).collect();
     let floating = data
         .floating
         .map(|layers| layers.iter().map(|l| asset_server.load(l)).collect());
     commands.insert_resource(LayerDataCache::<T> {
+        categories,
         floating,
         base,
         ..default()
@@ -312,8 +581,153 @@ fn cache_layer_data<T>(
     commands.remove_resource::<LayerHandle<T>>();
 }
 
-/// Cache data from [`TileData`] in [`TileDataCache`] and [`LevelDimensions`].
-fn cache_tile_data_and_related<T>(
+/// Cache data from [`LootData`] in [`LootDataCache`].
+fn cache_loot_data<T>(
+    mut commands: Commands,
+    mut data: ResMut<Assets<LootData<T>>>,
+    handle: Res<LootHandle<T>>,
+) where
+    T: Visible,
+{
+    let data = data.remove(handle.0.id()).expect(ERR_LOADING_LOOT_DATA);
+    commands.insert_resource(LootDataCache::<T> {
+        drops: data.drops,
+        ..default()
+    });
+
+    // Remove handle after caching since it is no longer needed
+    commands.remove_resource::<LootHandle<T>>();
+}
+
+/// Cache data from [`MapData`] in [`MapDataCache`].
+///
+/// Tiles are resolved from [`TileDataCache<A>`].
+fn cache_map_data<T, A>(
+    mut commands: Commands,
+    mut data: ResMut<Assets<MapData<T>>>,
+    handle: Res<MapHandle<T>>,
+    tile_data: Res<TileDataCache<A>>,
+) where
+    T: Level,
+    A: ProcGenerated,
+{
+    let data = data.remove(handle.0.id()).expect(ERR_LOADING_MAP_DATA);
+    commands.insert_resource(MapDataCache::<T> {
+        map: data.map.resolve(&tile_data),
+        ..default()
+    });
+
+    // Remove handle after caching since it is no longer needed
+    commands.remove_resource::<MapHandle<T>>();
+}
+
+/// Cache data from [`ProcGenData`] in [`ProcGenDataCache`].
+fn cache_procgen_data<T>(
+    mut commands: Commands,
+    mut data: ResMut<Assets<ProcGenData<T>>>,
+    handle: Res<ProcGenHandle<T>>,
+) where
+    T: ProcGenerated,
+{
+    let data = data.remove(handle.0.id()).expect(ERR_LOADING_PROCGEN_DATA);
+    if data.chunk_size.is_none() || data.procgen_distance.is_none() {
+        warn_once!("{}", WARN_INCOMPLETE_PROCGEN_DATA);
+    }
+    let fallback = ProcGenDataCache::<T>::default();
+    commands.insert_resource(ProcGenDataCache::<T> {
+        chunk_size: data
+            .chunk_size
+            .map_or(fallback.chunk_size, |(x, y)| UVec2::new(x, y)),
+        procgen_distance: data.procgen_distance.unwrap_or(fallback.procgen_distance),
+        ..fallback
+    });
+
+    // Remove handle after caching since it is no longer needed
+    commands.remove_resource::<ProcGenHandle<T>>();
+}
+
+/// Cache data from [`ProgressionData`] in [`ProgressionDataCache`].
+fn cache_progression_data<T>(
+    mut commands: Commands,
+    mut data: ResMut<Assets<ProgressionData<T>>>,
+    asset_server: Res<AssetServer>,
+    handle: Res<ProgressionHandle<T>>,
+) where
+    T: Visible,
+{
+    let data = data
+        .remove(handle.0.id())
+        .expect(ERR_LOADING_PROGRESSION_DATA);
+    let sound_handles = data
+        .sounds
+        .iter()
+        .map(|path| asset_server.load(path))
+        .collect();
+    commands.insert_resource(ProgressionDataCache::<T> {
+        levels: data.levels,
+        sound_handles,
+        ..default()
+    });
+
+    // Remove handle after caching since it is no longer needed
+    commands.remove_resource::<ProgressionHandle<T>>();
+}
+
+/// Cache data from [`PropData`] in [`PropDataCache`].
+fn cache_prop_data<T>(
+    mut commands: Commands,
+    mut data: ResMut<Assets<PropData<T>>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    asset_server: Res<AssetServer>,
+    handle: Res<PropHandle<T>>,
+) where
+    T: ProcGenerated,
+{
+    let data = data.remove(handle.0.id()).expect(ERR_LOADING_PROP_DATA);
+    let (street_light, random): (Vec<_>, Vec<_>) = data
+        .props
+        .into_iter()
+        .map(|mut prop| {
+            prop.image_handle = prop.image.as_ref().map(|i| asset_server.load(i));
+            prop.shadow = meshes.add(Ellipse::new(prop.width / 2., prop.height / 2.));
+            prop
+        })
+        .partition(|prop| prop.street_light);
+    commands.insert_resource(PropDataCache::<T> {
+        random,
+        street_light: street_light.into_iter().next(),
+        ..default()
+    });
+
+    // Remove handle after caching since it is no longer needed
+    commands.remove_resource::<PropHandle<T>>();
+}
+
+/// Cache data from [`StampData`] in [`StampDataCache`].
+///
+/// Tiles are resolved from [`TileDataCache`].
+fn cache_stamp_data<T>(
+    mut commands: Commands,
+    mut data: ResMut<Assets<StampData<T>>>,
+    handle: Res<StampHandle<T>>,
+    tile_data: Res<TileDataCache<T>>,
+) where
+    T: ProcGenerated,
+{
+    let data = data.remove(handle.0.id()).expect(ERR_LOADING_STAMP_DATA);
+    let maps = data
+        .stamps
+        .iter()
+        .map(|stamp| (stamp.chunk_pos.into(), stamp.map.resolve(&tile_data)))
+        .collect();
+    commands.insert_resource(StampDataCache::<T> { maps, ..default() });
+
+    // Remove handle after caching since it is no longer needed
+    commands.remove_resource::<StampHandle<T>>();
+}
+
+/// Cache data from [`TileData`] in [`TileDataCache`].
+fn cache_tile_data<T>(
     mut commands: Commands,
     mut data: ResMut<Assets<TileData<T>>>,
     handle: Res<TileHandle<T>>,
@@ -323,22 +737,32 @@ fn cache_tile_data_and_related<T>(
     let data = data.remove(handle.0.id()).expect(ERR_LOADING_TILE_DATA);
     commands.insert_resource(TileDataCache::<T> {
         tile_size: data.tile_size,
-        _full_dirt: data.full_dirt,
-        _full_grass: data.full_grass,
-        _corner_outer_grass_to_dirt: data.corner_outer_grass_to_dirt,
-        _corner_outer_dirt_to_grass: data.corner_outer_dirt_to_grass,
-        _side_dirt_and_grass: data.side_dirt_and_grass,
-        _diag_stripe_grass_in_dirt: data.diag_stripe_grass_in_dirt,
+        full_dirt: data.full_dirt,
+        full_grass: data.full_grass,
+        corner_outer_grass_to_dirt: data.corner_outer_grass_to_dirt,
+        corner_outer_dirt_to_grass: data.corner_outer_dirt_to_grass,
+        side_dirt_and_grass: data.side_dirt_and_grass,
+        diag_stripe_grass_in_dirt: data.diag_stripe_grass_in_dirt,
         ..default()
     });
-    let chunk_size_px = CHUNK_SIZE.as_vec2() * data.tile_size;
-    let world_height = PROCGEN_DISTANCE as f32 * 2. + 1. * chunk_size_px.y;
+
+    // Remove handle after caching since it is no longer needed
+    commands.remove_resource::<TileHandle<T>>();
+}
+
+/// Cache [`LevelDimensions`] from [`ProcGenDataCache`] and [`TileDataCache`].
+fn cache_level_dimensions<T>(
+    mut commands: Commands,
+    config: Res<ProcGenDataCache<T>>,
+    tile_data: Res<TileDataCache<T>>,
+) where
+    T: ProcGenerated,
+{
+    let chunk_size_px = config.chunk_size.as_vec2() * tile_data.tile_size;
+    let world_height = config.num_chunks() as f32 * chunk_size_px.y;
     commands.insert_resource(LevelDimensions::<T> {
         chunk_size_px,
         world_height,
         ..default()
     });
-
-    // Remove handle after caching since it is no longer needed
-    commands.remove_resource::<TileHandle<T>>();
 }
@@ -30,7 +30,7 @@ pub(crate) mod prelude {
         exit_menus_on_click,
     };
     pub(crate) use super::palette::*;
-    pub(crate) use super::widgets::bar::BarBuilder;
+    pub(crate) use super::widgets::bar::{BarBuilder, bar_mask_node};
     pub(crate) use super::widgets::button::{
         ButtonConfig, ButtonContainer, ButtonNodeConfig, ButtonText, MEDIUM_BUTTON_WIDTH, button,
         switch,
@@ -1,3 +1,5 @@
+mod boss;
+mod experience;
 mod health;
 pub(super) mod joystick;
 
@@ -38,7 +40,11 @@ impl Plugin for HudPlugin {
 
         app.add_systems(
             Update,
-            health::update_health_bar
+            (
+                health::update_health_bar,
+                experience::update_experience_bar,
+                boss::update_boss_bar,
+            )
                 .run_if(in_state(Screen::Gameplay))
                 .in_set(AppSystems::Update),
         );
@@ -68,6 +74,7 @@ pub(crate) enum Hud {
     TopRight,
     BottomLeft,
     BottomRight,
+    Bottom,
 }
 
 /// [`Node::row_gap`] for [`Hud::Root`].
@@ -80,12 +87,13 @@ const HUD_PADDING_PERCENT: f32 = 10.;
 ///
 /// The [`Hud`] is the main way to display UI outside of menus.
 ///
-/// It has a padding on each side and is separated into four sections that content can be added to:
+/// It has a padding on each side and is separated into five sections that content can be added to:
 ///
 /// - [`Hud::TopLeft`]
 /// - [`Hud::TopRight`]
 /// - [`Hud::BottomLeft`]
 /// - [`Hud::BottomRight`]
+/// - [`Hud::Bottom`] that spans the full width below the other sections
 fn spawn_hud(
     mut commands: Commands,
     #[cfg(any(target_os = "android", target_os = "ios"))] font: Res<UiFontHandle>,
@@ -100,6 +108,7 @@ fn spawn_hud(
             padding: UiRect::all(vmin(HUD_PADDING_PERCENT)),
             display: Display::Grid,
             grid_template_columns: RepeatedGridTrack::percent(2, 50.),
+            grid_template_rows: vec![RepeatedGridTrack::flex(2, 1.), RepeatedGridTrack::auto(1)],
             ..default()
         },
         Pickable::IGNORE,
@@ -114,7 +123,7 @@ fn spawn_hud(
                     row_gap: HUD_ROW_GAP,
                     ..default()
                 },
-                children![health::health_bar()],
+                children![health::health_bar(), experience::experience_bar()],
             ),
             (
                 Hud::TopRight,
@@ -150,6 +159,18 @@ fn spawn_hud(
                     row_gap: HUD_ROW_GAP,
                     ..default()
                 },
+            ),
+            (
+                Hud::Bottom,
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    grid_column: GridPlacement::span(2),
+                    row_gap: HUD_ROW_GAP,
+                    ..default()
+                },
+                children![boss::boss_bar()],
             )
         ],
     ));
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+use crate::{characters::prelude::*, ui::prelude::*};
+
+/// Boss health bar for the [`Hud`].
+#[derive(Component)]
+pub(super) struct HudBossBar;
+
+/// Health bar showing the current [`Health`] of the [`Boss`] of the [`BossFight`].
+pub(super) fn boss_bar() -> impl Bundle {
+    let bar = BarBuilder::round_big_hud()
+        .with_bar_background(BOSS_HEALTH_BAR_BACKGROUND)
+        .build();
+
+    (HudBossBar, Visibility::Hidden, bar)
+}
+
+/// Update boss bar from [`Boss`] [`Health`].
+///
+/// The bar is only visible during a [`BossFight`].
+pub(super) fn update_boss_bar(
+    bar_container: Single<(&mut Visibility, &Children), With<HudBossBar>>,
+    health_query: Query<&Health, With<Boss>>,
+    children_query: Query<&Children>,
+    mut node_query: Query<&mut Node>,
+    fight: Option<Res<BossFight>>,
+) {
+    let (mut visibility, children) = bar_container.into_inner();
+    let Some(health) = fight.and_then(|f| health_query.get(f.boss).ok()) else {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
+    visibility.set_if_neq(Visibility::Inherited);
+
+    let mut mask_node = bar_mask_node(children, &children_query, &mut node_query);
+    mask_node.width = percent((1. - health.fraction()) * 100.);
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use crate::{characters::prelude::*, ui::prelude::*};
+
+/// Experience bar for the [`Hud`].
+#[derive(Component)]
+pub(super) struct HudExperienceBar;
+
+/// Experience bar showing the progress of the [`Player`] to the next level.
+pub(super) fn experience_bar() -> impl Bundle {
+    let bar = BarBuilder::round_small_hud()
+        .with_bar_background(EXPERIENCE_BAR_BACKGROUND)
+        .build();
+
+    (HudExperienceBar, bar)
+}
+
+/// Update experience bar from [`Player`] [`Experience`].
+pub(super) fn update_experience_bar(
+    experience: Single<&Experience, (With<Player>, Changed<Experience>)>,
+    children: Single<&Children, With<HudExperienceBar>>,
+    children_query: Query<&Children>,
+    mut node_query: Query<&mut Node>,
+    progression: Res<ProgressionDataCache<Player>>,
+) {
+    let mut mask_node = bar_mask_node(&children, &children_query, &mut node_query);
+    mask_node.width = percent((1. - progression.fraction(&experience)) * 100.);
+}
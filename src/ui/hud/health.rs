@@ -1,10 +1,15 @@
 use bevy::prelude::*;
 
-use crate::{characters::prelude::*, log::prelude::*, ui::prelude::*};
+use crate::{characters::prelude::*, ui::prelude::*};
+
+/// Fraction of the [`HudHealthBar`] that is filled per second when [`Health`] is gained.
+const HEALTH_BAR_FILL_PER_SEC: f32 = 0.5;
 
 /// Health bar for the [`Hud`].
+///
+/// Contains the currently displayed [`Health::fraction`].
 #[derive(Component)]
-pub(super) struct HudHealthBar;
+pub(super) struct HudHealthBar(f32);
 
 /// Health bar showing the current [`Health`] of the [`Player`].
 pub(super) fn health_bar() -> impl Bundle {
@@ -12,29 +17,38 @@ pub(super) fn health_bar() -> impl Bundle {
         .with_bar_background(HEALTH_BAR_BACKGROUND)
         .build();
 
-    (HudHealthBar, NodeRect::default(), Visibility::Hidden, bar)
+    (
+        HudHealthBar(1.),
+        NodeRect::default(),
+        Visibility::Hidden,
+        bar,
+    )
 }
 
 /// Update health bar from [`Player`] [`Health`].
+///
+/// Losses are displayed immediately, while gains fill the bar with [`HEALTH_BAR_FILL_PER_SEC`].
 pub(super) fn update_health_bar(
-    health: Single<&Health, (Changed<Health>, With<Player>)>,
-    bar_container: Single<(&mut Visibility, &Children), With<HudHealthBar>>,
+    health: Single<&Health, With<Player>>,
+    bar_container: Single<(&mut HudHealthBar, &mut Visibility, &Children)>,
     children_query: Query<&Children>,
     mut node_query: Query<&mut Node>,
+    time: Res<Time>,
 ) {
-    let (mut visibility, children) = bar_container.into_inner();
-    let child = children
-        .iter()
-        .find(|e| children_query.contains(*e))
-        .expect(ERR_INVALID_CHILDREN);
-    let children = children_query.get(child).expect(ERR_INVALID_CHILDREN);
-    let child = children
-        .iter()
-        .find(|e| node_query.contains(*e))
-        .expect(ERR_INVALID_CHILDREN);
-    let mut mask_node = node_query.get_mut(child).expect(ERR_INVALID_CHILDREN);
-
-    let mask_percent = (1. - health.fraction()) * 100.;
+    let (mut bar, mut visibility, children) = bar_container.into_inner();
+    let fraction = health.fraction();
+    if bar.0 == fraction {
+        return;
+    }
+    bar.0 = if fraction < bar.0 {
+        fraction
+    } else {
+        (bar.0 + HEALTH_BAR_FILL_PER_SEC * time.delta_secs()).min(fraction)
+    };
+
+    let mut mask_node = bar_mask_node(children, &children_query, &mut node_query);
+
+    let mask_percent = (1. - bar.0) * 100.;
     *visibility = if mask_percent > 0. {
         Visibility::Inherited
     } else {
@@ -6,22 +6,36 @@
 //! The game's menus and transitions between them.
 
 pub(super) mod credits;
+mod game_over;
+mod inventory;
 mod pause;
 mod settings;
 
 use bevy::{input::common_conditions::input_just_pressed, prelude::*};
 
-use crate::{screens::prelude::*, ui::prelude::*};
+use crate::{save::prelude::*, screens::prelude::*, ui::prelude::*};
 
 pub(super) struct MenusPlugin;
 impl Plugin for MenusPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((pause::PausePlugin, settings::SettingsPlugin));
+        app.add_plugins((
+            inventory::InventoryPlugin,
+            pause::PausePlugin,
+            settings::SettingsPlugin,
+        ));
 
         app.init_state::<Menu>();
 
         app.add_systems(OnExit(Screen::Gameplay), exit_menus);
         app.add_systems(OnEnter(Menu::Main), spawn_main_menu);
+        app.add_systems(
+            OnEnter(Menu::GameOver),
+            (pause::pause, game_over::spawn_game_over_menu),
+        );
+        app.add_systems(
+            OnEnter(Menu::Inventory),
+            (pause::pause, inventory::spawn_inventory_menu),
+        );
         app.add_systems(
             OnEnter(Menu::Credits),
             (credits::spawn_credits_menu, credits::start_credits_music),
@@ -31,10 +45,13 @@ impl Plugin for MenusPlugin {
             Update,
             exit_menus.run_if(
                 not(in_state(Menu::None))
+                    .and(not(in_state(Menu::GameOver)))
                     .and(input_just_pressed(KeyCode::KeyP))
                     .and(in_state(Screen::Gameplay)),
             ),
         );
+        app.add_observer(game_over::enter_game_over_menu);
+
         app.add_systems(
             Update,
             enter_main_menu
@@ -52,6 +69,8 @@ pub(crate) enum Menu {
     Credits,
     Settings,
     Pause,
+    Inventory,
+    GameOver,
 }
 
 /// Spawn Main menu with [`State`] changing buttons.
@@ -61,7 +80,7 @@ fn spawn_main_menu(mut commands: Commands, font: Res<UiFontHandle>) {
             .with_text("Play")
             .with_header_font(font.0.clone()),
         ButtonNodeConfig::round_big(),
-        enter_gameplay_screen_on_click,
+        enter_character_creator_screen_on_click,
     );
     let button_settings = button(
         ButtonConfig::navigable()
@@ -86,16 +105,42 @@ fn spawn_main_menu(mut commands: Commands, font: Res<UiFontHandle>) {
         exit_app_on_click,
     );
 
-    commands.spawn((
-        root_widget("Main Menu"),
-        GlobalZIndex(2),
-        DespawnOnExit(Menu::Main),
-        #[cfg(not(any(target_family = "wasm", target_os = "android", target_os = "ios")))]
-        children![button_play, button_settings, button_credits, button_exit],
-        // Do not add exit button for wasm, android and ios
-        #[cfg(any(target_family = "wasm", target_os = "android", target_os = "ios"))]
-        children![button_play, button_settings, button_credits],
-    ));
+    let menu = commands
+        .spawn((
+            root_widget("Main Menu"),
+            GlobalZIndex(2),
+            DespawnOnExit(Menu::Main),
+            #[cfg(not(any(target_family = "wasm", target_os = "android", target_os = "ios")))]
+            children![button_play, button_settings, button_credits, button_exit],
+            // Do not add exit button for wasm, android and ios
+            #[cfg(any(target_family = "wasm", target_os = "android", target_os = "ios"))]
+            children![button_play, button_settings, button_credits],
+        ))
+        .id();
+
+    // Add buttons for existing save files before all other buttons
+    let mut save_buttons = Vec::new();
+    if SaveSlot::Auto.exists() {
+        let button_continue = button(
+            ButtonConfig::navigable()
+                .with_text("Continue")
+                .with_header_font(font.0.clone()),
+            ButtonNodeConfig::round_big(),
+            load_game_on_click::<false>,
+        );
+        save_buttons.push(commands.spawn(button_continue).id());
+    }
+    if SaveSlot::Manual.exists() {
+        let button_load = button(
+            ButtonConfig::navigable()
+                .with_text("Load")
+                .with_header_font(font.0.clone()),
+            ButtonNodeConfig::round_big(),
+            load_game_on_click::<true>,
+        );
+        save_buttons.push(commands.spawn(button_load).id());
+    }
+    commands.entity(menu).insert_children(0, &save_buttons);
 }
 
 /// Exit [`Menu`]s.
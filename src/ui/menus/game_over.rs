@@ -0,0 +1,48 @@
+//! The game over menu.
+
+use bevy::prelude::*;
+
+use crate::{characters::prelude::*, levels::prelude::*, screens::prelude::*, ui::prelude::*};
+
+/// Enter [`Menu::GameOver`] on [`PlayerDied`].
+pub(super) fn enter_game_over_menu(_: On<PlayerDied>, mut next_state: ResMut<NextState<Menu>>) {
+    (*next_state).set_if_neq(Menu::GameOver);
+}
+
+/// Spawn game over menu.
+pub(super) fn spawn_game_over_menu(mut commands: Commands, font: Res<UiFontHandle>) {
+    let button_retry = button(
+        ButtonConfig::navigable()
+            .with_text("Retry")
+            .with_header_font(font.0.clone()),
+        ButtonNodeConfig::round_big(),
+        retry_on_click,
+    );
+    let button_main_menu = button(
+        ButtonConfig::navigable()
+            .with_text("Main Menu")
+            .with_header_font(font.0.clone()),
+        ButtonNodeConfig::round_big(),
+        enter_title_screen_on_click,
+    );
+    commands.spawn((
+        root_widget("Game Over Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::GameOver),
+        children![
+            header_widget("Game over", font.0.clone()),
+            button_retry,
+            button_main_menu,
+        ],
+    ));
+}
+
+/// Trigger [`RespawnPlayer`] and exit [`Menu`]s on [`Pointer`] click.
+fn retry_on_click(
+    _: On<Pointer<Click>>,
+    mut commands: Commands,
+    next_state: ResMut<NextState<Menu>>,
+) {
+    commands.trigger(RespawnPlayer);
+    exit_menus(next_state);
+}
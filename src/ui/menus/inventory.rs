@@ -0,0 +1,175 @@
+//! The inventory menu.
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+
+use crate::{characters::prelude::*, log::prelude::*, screens::prelude::*, ui::prelude::*};
+
+pub(super) struct InventoryPlugin;
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            exit_menus
+                .run_if(in_state(Menu::Inventory).and(
+                    input_just_pressed(KeyCode::KeyI).or(input_just_pressed(KeyCode::Escape)),
+                )),
+        );
+        app.add_systems(
+            Update,
+            enter_inventory_menu.run_if(
+                in_state(Menu::None)
+                    .and(input_just_pressed(KeyCode::KeyI))
+                    .and(in_state(Screen::Gameplay)),
+            ),
+        );
+        app.add_systems(
+            Update,
+            (update_equipment_labels, update_equipment_stats_label)
+                .run_if(in_state(Menu::Inventory)),
+        );
+    }
+}
+
+/// Label marker that displays the equipped [`Item`] of the contained [`EquipmentSlot`].
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct EquipmentLabel(EquipmentSlot);
+
+/// Label marker that displays the [`Player`]'s stats.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct EquipmentStatsLabel;
+
+/// Enter [`Menu::Inventory`].
+fn enter_inventory_menu(mut next_state: ResMut<NextState<Menu>>) {
+    (*next_state).set_if_neq(Menu::Inventory);
+}
+
+/// Spawn inventory menu.
+pub(super) fn spawn_inventory_menu(mut commands: Commands, font: Res<UiFontHandle>) {
+    let button = button(
+        ButtonConfig::navigable()
+            .with_text("Back")
+            .with_header_font(font.0.clone()),
+        ButtonNodeConfig::round_big(),
+        exit_menus_on_click,
+    );
+
+    commands.spawn((
+        root_widget("Inventory Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::Inventory),
+        children![
+            header_widget("Inventory", font.0.clone()),
+            equipment_grid(font.0.clone()),
+            (EquipmentStatsLabel, label_widget("", font.0.clone())),
+            button,
+        ],
+    ));
+}
+
+/// Grid with a row for each [`EquipmentSlot`].
+fn equipment_grid(font: Handle<Font>) -> impl Bundle {
+    (
+        Name::new("Equipment Grid"),
+        Node {
+            width: px(ROOT_MAX_ELEMENT_WIDTH_PX),
+            display: Display::Grid,
+            row_gap: px(BODY_FONT_SIZE),
+            grid_template_columns: RepeatedGridTrack::px(2, ROOT_MAX_ELEMENT_WIDTH_PX / 2.),
+            ..default()
+        },
+        children![
+            equipment_label(font.clone(), EquipmentSlot::Head),
+            equipment_widget::<{ EquipmentSlot::HEAD }>(font.clone()),
+            equipment_label(font.clone(), EquipmentSlot::Back),
+            equipment_widget::<{ EquipmentSlot::BACK }>(font.clone()),
+            equipment_label(font.clone(), EquipmentSlot::Hands),
+            equipment_widget::<{ EquipmentSlot::HANDS }>(font.clone()),
+            equipment_label(font.clone(), EquipmentSlot::Feet),
+            equipment_widget::<{ EquipmentSlot::FEET }>(font.clone()),
+        ],
+    )
+}
+
+/// Label for an [`EquipmentSlot`].
+fn equipment_label(font: Handle<Font>, slot: EquipmentSlot) -> impl Bundle {
+    (
+        Node {
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::End,
+            ..default()
+        },
+        children![label_widget(slot.name(), font.clone())],
+    )
+}
+
+/// Widget to cycle the equipped [`Item`] of [`EquipmentSlot`] with `const SLOT`.
+fn equipment_widget<const SLOT: u8>(font: Handle<Font>) -> impl Bundle {
+    let slot = EquipmentSlot::try_from(SLOT).expect(ERR_INVALID_ENUM_PRIMITVE);
+    let button_next = button(
+        ButtonConfig::navigable()
+            .with_text(">")
+            .with_body_font(font.clone()),
+        ButtonNodeConfig::circle_small(),
+        cycle_equipment_on_click::<SLOT>,
+    );
+
+    (
+        Name::new("Equipment Widget"),
+        Node {
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        children![
+            (
+                Name::new("Equipped Item"),
+                Node {
+                    padding: UiRect::horizontal(px(10)),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                children![(EquipmentLabel(slot), label_widget("", font.clone()))],
+            ),
+            button_next,
+        ],
+    )
+}
+
+/// Equip the next [`Item`] of [`EquipmentSlot`] with `const SLOT`.
+fn cycle_equipment_on_click<const SLOT: u8>(
+    _: On<Pointer<Click>>,
+    player: Single<(&Inventory, &mut Equipment), With<Player>>,
+    item_data: Res<ItemDataCache<Player>>,
+) {
+    let slot = EquipmentSlot::try_from(SLOT).expect(ERR_INVALID_ENUM_PRIMITVE);
+    let (inventory, mut equipment) = player.into_inner();
+    equipment.cycle(slot, inventory, &item_data.items);
+}
+
+/// Update labels that display equipped [`Item`]s.
+fn update_equipment_labels(
+    mut label_query: Query<(&mut Text, &EquipmentLabel)>,
+    equipment: Single<&Equipment, With<Player>>,
+    item_data: Res<ItemDataCache<Player>>,
+) {
+    for (mut text, label) in &mut label_query {
+        text.0 = equipment
+            .slots
+            .get(&label.0)
+            .and_then(|i| item_data.items.get(*i))
+            .map_or("None".to_string(), |i| i.name.clone());
+    }
+}
+
+/// Update label that displays the [`Player`]'s stats.
+fn update_equipment_stats_label(
+    mut label: Single<&mut Text, With<EquipmentStatsLabel>>,
+    player: Single<(&AttackStats, &Health), With<Player>>,
+) {
+    let (stats, health) = *player;
+    label.0 = format!(
+        "Damage: x{:.2}  Max health: {:.0}",
+        stats.damage_factor, health.max
+    );
+}
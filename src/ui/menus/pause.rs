@@ -7,7 +7,10 @@
 
 use bevy::{input::common_conditions::input_just_pressed, prelude::*};
 
-use crate::{core::prelude::*, screens::prelude::*, ui::prelude::*, utils::prelude::*};
+use crate::{
+    core::prelude::*, levels::prelude::*, procgen::prelude::*, save::prelude::*,
+    screens::prelude::*, ui::prelude::*, utils::prelude::*,
+};
 
 pub(super) struct PausePlugin;
 impl Plugin for PausePlugin {
@@ -56,7 +59,17 @@ fn spawn_pause_overlay(mut commands: Commands) {
 }
 
 /// Spawn pause menu.
-fn spawn_pause_menu(mut commands: Commands, font: Res<UiFontHandle>) {
+fn spawn_pause_menu(
+    mut commands: Commands,
+    biomes: Res<BiomeDataCache<OverworldProcGen>>,
+    current_biome: Res<CurrentBiome<OverworldProcGen>>,
+    font: Res<UiFontHandle>,
+    seed: Res<WorldSeed>,
+) {
+    let biome = current_biome
+        .index
+        .and_then(|i| biomes.biomes.get(i))
+        .map_or("Unknown", |b| b.name.as_str());
     let button_continue = button(
         ButtonConfig::navigable()
             .with_text("Continue")
@@ -64,6 +77,13 @@ fn spawn_pause_menu(mut commands: Commands, font: Res<UiFontHandle>) {
         ButtonNodeConfig::round_big(),
         exit_menus_on_click,
     );
+    let button_save = button(
+        ButtonConfig::navigable()
+            .with_text("Save")
+            .with_header_font(font.0.clone()),
+        ButtonNodeConfig::round_big(),
+        save_game_on_click,
+    );
     let button_settings = button(
         ButtonConfig::navigable()
             .with_text("Settings")
@@ -76,7 +96,7 @@ fn spawn_pause_menu(mut commands: Commands, font: Res<UiFontHandle>) {
             .with_text("Quit to title")
             .with_header_font(font.0.clone()),
         ButtonNodeConfig::round_big(),
-        enter_title_screen_on_click,
+        save_and_enter_title_screen_on_click,
     );
     commands.spawn((
         root_widget("Pause Menu"),
@@ -84,7 +104,10 @@ fn spawn_pause_menu(mut commands: Commands, font: Res<UiFontHandle>) {
         DespawnOnExit(Menu::Pause),
         children![
             header_widget("Game paused", font.0.clone()),
+            label_widget(format!("Seed: {}", seed.0), font.0.clone()),
+            label_widget(format!("Biome: {biome}"), font.0.clone()),
             button_continue,
+            button_save,
             button_settings,
             button_quit,
         ],
@@ -97,6 +120,6 @@ fn unpause(mut next_state: ResMut<NextState<Pause>>) {
 }
 
 /// Pause the game
-fn pause(mut next_state: ResMut<NextState<Pause>>) {
+pub(super) fn pause(mut next_state: ResMut<NextState<Pause>>) {
     (*next_state).set_if_neq(Pause(true));
 }
@@ -14,6 +14,10 @@ pub(crate) const HEADER_TEXT: Color = Color::WHITE;
 pub(crate) const BAR_CONTAINER_BACKGROUND: Srgba = tailwind::SKY_100;
 /// Color for health bar background.
 pub(crate) const HEALTH_BAR_BACKGROUND: Srgba = tailwind::RED_500;
+/// Color for boss health bar background.
+pub(crate) const BOSS_HEALTH_BAR_BACKGROUND: Srgba = tailwind::PURPLE_500;
+/// Color for experience bar background.
+pub(crate) const EXPERIENCE_BAR_BACKGROUND: Srgba = tailwind::AMBER_400;
 
 /// Color for button text
 pub(crate) const BUTTON_TEXT: Color = Color::WHITE;
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::ui::prelude::*;
+use crate::{log::prelude::*, ui::prelude::*};
 
 /// A builder for creating bar [`Bundle`]s with customizable appearance.
 #[derive(Default)]
@@ -29,6 +29,14 @@ impl BarBuilder {
             ..default()
         }
     }
+    pub(crate) fn round_small_hud() -> Self {
+        Self {
+            width: px(HUD_MAX_ELEMENT_WIDTH_PX),
+            height: px(32),
+            padding: UiRect::all(px(10)),
+            ..default()
+        }
+    }
     pub(crate) fn with_position_type(self, position_type: PositionType) -> Self {
         Self {
             position_type,
@@ -82,3 +90,23 @@ impl BarBuilder {
         )
     }
 }
+
+/// Mask [`Node`] of a bar from [`BarBuilder::build`].
+///
+/// `children` are the [`Children`] of the bar container. The width of the mask is the empty part of the bar.
+pub(crate) fn bar_mask_node<'a>(
+    children: &Children,
+    children_query: &Query<&Children>,
+    node_query: &'a mut Query<&mut Node>,
+) -> Mut<'a, Node> {
+    let child = children
+        .iter()
+        .find(|e| children_query.contains(*e))
+        .expect(ERR_INVALID_CHILDREN);
+    let children = children_query.get(child).expect(ERR_INVALID_CHILDREN);
+    let child = children
+        .iter()
+        .find(|e| node_query.contains(*e))
+        .expect(ERR_INVALID_CHILDREN);
+    node_query.get_mut(child).expect(ERR_INVALID_CHILDREN)
+}
@@ -4,7 +4,7 @@
 
 use bevy::{platform::collections::HashMap, prelude::*};
 
-use crate::{characters::prelude::*, log::prelude::*, render::prelude::*, ui::prelude::*};
+use crate::{characters::prelude::*, render::prelude::*, ui::prelude::*};
 
 /// [`Node::width`] for [`WorldUiHealthBar`].
 pub(crate) const WORLD_UI_HEALTH_BAR_WIDTH: Val = Val::Px(42.);
@@ -81,16 +81,7 @@ pub(super) fn update_health_bar<T>(
         let Ok((mut visibility, children)) = bar_container_query.get_mut(*entity) else {
             continue;
         };
-        let child = children
-            .iter()
-            .find(|e| children_query.contains(*e))
-            .expect(ERR_INVALID_CHILDREN);
-        let children = children_query.get(child).expect(ERR_INVALID_CHILDREN);
-        let child = children
-            .iter()
-            .find(|e| node_query.contains(*e))
-            .expect(ERR_INVALID_CHILDREN);
-        let mut mask_node = node_query.get_mut(child).expect(ERR_INVALID_CHILDREN);
+        let mut mask_node = bar_mask_node(children, &children_query, &mut node_query);
 
         let mask_percent = (1. - health.fraction()) * 100.;
         *visibility = if mask_percent > 0. {
@@ -1,5 +1,6 @@
 mod color;
 mod images;
+mod noise;
 mod rng;
 mod run_conditions;
 mod timers;
@@ -8,6 +9,7 @@ mod timers;
 pub(crate) mod prelude {
     pub(crate) use super::color::{color_from_rgb, color_from_rgba};
     pub(crate) use super::images::{has_opaque_neighbor, is_transparent_pixel, pixel_index};
+    pub(crate) use super::noise::{fbm_2d, hash_2d, hash_str, value_noise_2d};
     pub(crate) use super::rng::{ForkedRng, setup_rng};
     pub(crate) use super::run_conditions::window_unfocused;
     pub(crate) use super::timers::{
@@ -0,0 +1,60 @@
+use bevy::{math::FloatExt as _, prelude::*};
+
+/// Hash of integer coordinates `x`, `y` with `seed`.
+///
+/// This is based on the finalizer of SplitMix64 and is stable across platforms and runs.
+pub(crate) fn hash_2d(seed: u64, x: i32, y: i32) -> u64 {
+    let mut h = seed
+        ^ (x as u32 as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (y as u32 as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    h = (h ^ (h >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    h = (h ^ (h >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    h ^ (h >> 31)
+}
+
+/// Hash of `text`.
+///
+/// This uses FNV-1a and is stable across platforms and runs, unlike [`std::hash::DefaultHasher`].
+pub(crate) fn hash_str(text: &str) -> u64 {
+    text.bytes().fold(0xCBF2_9CE4_8422_2325, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x0100_0000_01B3)
+    })
+}
+
+/// Value between 0 and 1 for integer coordinates `x`, `y` with `seed`.
+fn lattice_value(seed: u64, x: i32, y: i32) -> f32 {
+    // NOTE: We are only using the upper 24 bits since that is the precision of `f32`.
+    (hash_2d(seed, x, y) >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// Value noise between 0 and 1 at `pos` with `seed`.
+///
+/// Lattice values are interpolated with [`EaseFunction::SmoothStep`].
+pub(crate) fn value_noise_2d(seed: u64, pos: Vec2) -> f32 {
+    let cell = pos.floor();
+    let (x, y) = (cell.x as i32, cell.y as i32);
+    let t = pos - cell;
+    let (tx, ty) = (
+        EaseFunction::SmoothStep.sample_clamped(t.x),
+        EaseFunction::SmoothStep.sample_clamped(t.y),
+    );
+
+    let bottom = lattice_value(seed, x, y).lerp(lattice_value(seed, x + 1, y), tx);
+    let top = lattice_value(seed, x, y + 1).lerp(lattice_value(seed, x + 1, y + 1), tx);
+    bottom.lerp(top, ty)
+}
+
+/// Layered [`value_noise_2d`] between 0 and 1 at `pos` with `seed`.
+///
+/// Each octave doubles the frequency and halves the amplitude of the previous one.
+pub(crate) fn fbm_2d(seed: u64, pos: Vec2, octaves: u32) -> f32 {
+    let (mut total, mut amplitude, mut frequency, mut max) = (0., 1., 1., 0.);
+    for octave in 0..octaves {
+        total += value_noise_2d(seed.wrapping_add(octave as u64), pos * frequency) * amplitude;
+        max += amplitude;
+        amplitude /= 2.;
+        frequency *= 2.;
+    }
+
+    if max > 0. { total / max } else { 0. }
+}
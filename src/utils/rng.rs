@@ -5,7 +5,7 @@ use bevy_rand::{global::GlobalRng, traits::ForkableSeed as _};
 /// Applies to any rng that is forked from [`GlobalRng`]
 pub(crate) trait ForkedRng
 where
-    Self: Component + Default,
+    Self: Component + Default + TypePath,
 {
 }
 